@@ -0,0 +1,247 @@
+use crate::prelude::{
+    AlteredDegree, Cadence, Chord, Degree, Diatonic, DiatonicQuality, Formula, HarmonicFunction,
+    Quality, RomanNumeral, Tone,
+};
+
+/// A harmonic function in an analysis, which tells predominants from the subdominant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FunctionLabel {
+    Tonic,
+    Subdominant,
+    /// A chord that leads to the dominant other than IV, like ii, "N6" or "V/V".
+    Predominant,
+    Dominant,
+}
+
+/// How conclusive a cadence sounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CadenceStrength {
+    Weak,
+    Moderate,
+    Strong,
+}
+
+/// A cadence found in a progression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CadenceMatch {
+    cadence: Cadence,
+    position: usize,
+    strength: CadenceStrength,
+}
+
+impl CadenceMatch {
+    pub fn cadence(&self) -> Cadence {
+        self.cadence
+    }
+
+    /// Returns an index of the chord that the cadence arrives on.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn strength(&self) -> CadenceStrength {
+        self.strength
+    }
+}
+
+impl FunctionLabel {
+    /// Returns the function of `numeral`.
+    /// - ii, a secondary chord of V, the Neapolitan and augmented sixths are predominants,
+    ///   and the other secondary chords are dominants of their targets.
+    /// - A chromatic chord takes the function of its degree, like a subdominant for bVI.
+    pub fn of(numeral: &RomanNumeral) -> Self {
+        if numeral.get_special().is_some() {
+            return FunctionLabel::Predominant;
+        }
+        let degree = numeral.degree();
+        match numeral.secondary() {
+            Some(target) if target.degree().degree().value() == 5 => FunctionLabel::Predominant,
+            Some(_) => FunctionLabel::Dominant,
+            None if degree.degree().value() == 6 && degree.alteration() < 0 => {
+                FunctionLabel::Subdominant
+            }
+            None if degree == AlteredDegree::natural(Degree::new(2).unwrap()) => {
+                FunctionLabel::Predominant
+            }
+            None => HarmonicFunction::of(degree.degree()).into(),
+        }
+    }
+}
+
+impl From<HarmonicFunction> for FunctionLabel {
+    fn from(value: HarmonicFunction) -> Self {
+        match value {
+            HarmonicFunction::Tonic => FunctionLabel::Tonic,
+            HarmonicFunction::Subdominant => FunctionLabel::Subdominant,
+            HarmonicFunction::Dominant => FunctionLabel::Dominant,
+        }
+    }
+}
+
+/// Returns Roman numerals of `chords` in `key`, ignoring tensions.
+/// - Returns `None` for a chord that Roman numerals do not describe.
+pub fn analyze_numerals(chords: &[Chord<Tone>], key: &Diatonic) -> Vec<Option<RomanNumeral>> {
+    let key = Formula::from(key);
+    chords
+        .iter()
+        .map(|chord| {
+            let plain = Chord::new(*chord.root(), chord.quality());
+            let plain = match chord.bass() {
+                Some(bass) => plain.with_bass(*bass),
+                None => plain,
+            };
+            RomanNumeral::analyze(&plain, &key)
+        })
+        .collect()
+}
+
+/// Returns a harmonic function of each of `chords` in `key`.
+/// - Returns `None` for a chord that Roman numerals do not describe.
+pub fn label_functions(chords: &[Chord<Tone>], key: &Diatonic) -> Vec<Option<FunctionLabel>> {
+    analyze_numerals(chords, key)
+        .iter()
+        .map(|n| n.as_ref().map(FunctionLabel::of))
+        .collect()
+}
+
+/// Returns cadences in `chords` in `key`, in order of their positions.
+/// - An authentic cadence is strong if both chords are in root position, and moderate
+///   otherwise or from the leading-tone chord.
+/// - A half cadence is found on V that neither follows V nor resolves to I or vi.
+pub fn detect_cadences(chords: &[Chord<Tone>], key: &Diatonic) -> Vec<CadenceMatch> {
+    let numerals = analyze_numerals(chords, key);
+    let minor = key.quality() == DiatonicQuality::Minor;
+    let plain = |i: usize| {
+        numerals
+            .get(i)?
+            .as_ref()
+            .filter(|n| n.secondary().is_none())
+    };
+    let degree = |i: usize| plain(i).map(|n| n.degree().degree().value());
+    let root_position = |i: usize| plain(i).is_some_and(|n| n.inversion() == 0);
+    let dominant = |i: usize| {
+        plain(i).is_some_and(|n| match n.degree().degree().value() {
+            5 => matches!(n.quality(), Quality::Major | Quality::Dominant7),
+            7 => matches!(
+                n.quality(),
+                Quality::Dim | Quality::Dim7 | Quality::HalfDim7
+            ),
+            _ => false,
+        })
+    };
+    let is_v = |i: usize| dominant(i) && degree(i) == Some(5);
+
+    let mut cadences = vec![];
+    let mut push = |cadence, position, strength| {
+        cadences.push(CadenceMatch {
+            cadence,
+            position,
+            strength,
+        })
+    };
+    for i in 0..chords.len() {
+        let previous = i.checked_sub(1);
+        let from = |d: usize| previous.is_some_and(|p| degree(p) == Some(d));
+        match degree(i) {
+            Some(1) if previous.is_some_and(dominant) => {
+                let p = i - 1;
+                let strong = is_v(p) && root_position(p) && root_position(i);
+                let strength = match strong {
+                    true => CadenceStrength::Strong,
+                    false => CadenceStrength::Moderate,
+                };
+                push(Cadence::Authentic, i, strength);
+            }
+            Some(1) if from(4) && plain(i - 1).is_some_and(|n| n.get_special().is_none()) => {
+                let strength = match root_position(i - 1) && root_position(i) {
+                    true => CadenceStrength::Moderate,
+                    false => CadenceStrength::Weak,
+                };
+                push(Cadence::Plagal, i, strength);
+            }
+            Some(6) if previous.is_some_and(is_v) => {
+                push(Cadence::Deceptive, i, CadenceStrength::Moderate);
+            }
+            Some(5) if is_v(i) => {
+                let p = i.wrapping_sub(1);
+                let phrygian = minor
+                    && from(4)
+                    && plain(p)
+                        .is_some_and(|n| n.quality() == Quality::Minor && n.inversion() == 1);
+                let resolved = matches!(degree(i + 1), Some(1 | 6));
+                if phrygian {
+                    push(Cadence::Phrygian, i, CadenceStrength::Moderate);
+                } else if !resolved && !previous.is_some_and(is_v) {
+                    let strength = match root_position(i) {
+                        true => CadenceStrength::Moderate,
+                        false => CadenceStrength::Weak,
+                    };
+                    push(Cadence::Half, i, strength);
+                }
+            }
+            _ => {}
+        }
+    }
+    cadences
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn chords(symbols: &[&str]) -> Vec<Chord<Tone>> {
+        symbols.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn functions() {
+        let key = Diatonic::major(&"C".parse().unwrap());
+        let functions = label_functions(&chords(&["C", "Am", "Dm7", "D7", "G7", "C"]), &key);
+        use FunctionLabel::*;
+        assert_eq!(
+            functions,
+            [Tonic, Tonic, Predominant, Predominant, Dominant, Tonic].map(Some)
+        );
+        let functions = label_functions(&chords(&["F", "Ab", "E7", "Bdim"]), &key);
+        assert_eq!(
+            functions,
+            [Subdominant, Subdominant, Dominant, Dominant].map(Some)
+        );
+    }
+
+    #[test]
+    fn cadences() {
+        let key = Diatonic::major(&"C".parse().unwrap());
+        let found = detect_cadences(
+            &chords(&["C", "F", "G", "C", "Dm", "G", "Am", "F", "C", "G/B", "C/E"]),
+            &key,
+        );
+        let found: Vec<(Cadence, usize, CadenceStrength)> = found
+            .iter()
+            .map(|c| (c.cadence(), c.position(), c.strength()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Cadence::Authentic, 3, CadenceStrength::Strong),
+                (Cadence::Deceptive, 6, CadenceStrength::Moderate),
+                (Cadence::Plagal, 8, CadenceStrength::Moderate),
+                (Cadence::Authentic, 10, CadenceStrength::Moderate),
+            ]
+        );
+        let found = detect_cadences(&chords(&["C", "Am", "F", "G"]), &key);
+        assert_eq!(found[0].cadence(), Cadence::Half);
+        assert_eq!(found[0].position(), 3);
+    }
+
+    #[test]
+    fn minor() {
+        let key = Diatonic::minor(&"A".parse().unwrap());
+        let found = detect_cadences(&chords(&["Am", "Dm/F", "E"]), &key);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].cadence(), Cadence::Phrygian);
+        let found = detect_cadences(&chords(&["Am", "G#dim7", "Am"]), &key);
+        assert_eq!(found[0].cadence(), Cadence::Authentic);
+        assert_eq!(found[0].strength(), CadenceStrength::Moderate);
+    }
+}
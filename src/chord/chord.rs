@@ -3,20 +3,125 @@ use std::collections::HashMap;
 use once_cell::sync::Lazy;
 
 use crate::{
+    chord::voicing::{class, number},
     core::Chord as CoreChord,
     core::*,
-    prelude::{Tone, Twelve},
+    prelude::{AccidentalSymbol, ChordFormula, Tension, Tensions, Tone, ToneSymbol, Twelve},
 };
 
+/// A named chord like "Cm7(9)/G", which is a root, a `Quality`, tensions and a bass.
+/// - It stays a separate type from `core::Chord`, which holds the tones themselves.
+///   `to_class` and `to_pitch` realize it on any `Keyboard`, and `from_class` and
+///   `from_pitch` name one back.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Chord<T> {
     root: T,
     quality: Quality,
+    tensions: Tensions,
+    bass: Option<T>,
 }
 
 impl<T> Chord<T> {
     pub fn new(root: T, quality: Quality) -> Self {
-        Self { root, quality }
+        Self {
+            root,
+            quality,
+            tensions: Tensions::new(),
+            bass: None,
+        }
+    }
+
+    /// Returns a slash chord whose bass is given `bass`, like "C/E".
+    pub fn with_bass(self, bass: T) -> Self {
+        Self {
+            bass: Some(bass),
+            ..self
+        }
+    }
+
+    /// Returns this chord with `tensions`.
+    /// - A minor 7th chord with the flat 5th becomes a half-diminished 7th chord, as its
+    ///   symbol like "Cm7(b5)" reads.
+    pub fn with_tensions(self, tensions: Tensions) -> Self {
+        Self { tensions, ..self }.canonical()
+    }
+
+    /// Returns this chord with `tension` added, as `with_tensions` does.
+    pub fn with_tension(self, tension: Tension) -> Self {
+        let tensions = self.tensions.with(tension);
+        Self { tensions, ..self }.canonical()
+    }
+
+    pub fn root(&self) -> &T {
+        &self.root
+    }
+
+    pub fn quality(&self) -> Quality {
+        self.quality
+    }
+
+    pub fn tensions(&self) -> Tensions {
+        self.tensions
+    }
+
+    /// Returns the bass of a slash chord, or `None` if the bass is not specified.
+    pub fn bass(&self) -> Option<&T> {
+        self.bass.as_ref()
+    }
+
+    /// Returns a `Chord` whose chord tones are given intervals from the root,
+    /// which are in semitones and may exceed an octave.
+    /// - The simplest interpretation is picked, which has the fewest tensions
+    ///   and the most common quality.
+    /// - Returns `None` if no quality and tensions match.
+    pub fn from_intervals(root: T, intervals: &[Interval]) -> Option<Self> {
+        let mut target = intervals.to_vec();
+        target.sort();
+        target.dedup();
+        let candidates: Vec<Tension> = Tension::enumerate()
+            .filter(|t| target.contains(&t.interval()))
+            .copied()
+            .collect();
+        let subsets = (0..1_u32 << candidates.len()).map(|bits| {
+            (0..candidates.len())
+                .filter(|i| bits & (1 << i) != 0)
+                .map(|i| candidates[i])
+                .collect::<Tensions>()
+        });
+        let mut subsets: Vec<Tensions> = subsets.collect();
+        subsets.sort_by_key(|t| t.len());
+        let (quality, tensions) = subsets.into_iter().find_map(|tensions| {
+            Quality::enumerate()
+                .find(|q| {
+                    let chord = Chord::new((), **q).with_tensions(tensions);
+                    chord.intervals() == target
+                })
+                .map(|q| (*q, tensions))
+        })?;
+        Some(Self::new(root, quality).with_tensions(tensions))
+    }
+
+    /// Returns intervals from the root of the other chord tones, in ascending order.
+    /// - An altering tension like "b9" replaces the tone it alters.
+    pub fn intervals(&self) -> Vec<Interval> {
+        let mut intervals = self.quality.intervals().to_vec();
+        for tension in self.tensions.iter() {
+            if let Some(replaced) = tension.replaces() {
+                intervals.retain(|i| i != &replaced);
+            }
+            intervals.push(tension.interval());
+        }
+        intervals.sort();
+        intervals.dedup();
+        intervals
+    }
+
+    fn canonical(mut self) -> Self {
+        if self.quality == Quality::Minor7 && self.tensions.contains(Tension::FlatFive) {
+            self.quality = Quality::HalfDim7;
+            self.tensions.remove(Tension::FlatFive);
+        }
+        self
     }
 }
 
@@ -26,18 +131,69 @@ pub enum Quality {
     Minor,
     Dim,
     Aug,
+    Sus2,
+    Sus4,
+    Power,
+    Major6,
+    Minor6,
+    SixNine,
+    MinorSixNine,
     Major7,
     Minor7,
+    Dominant7,
+    HalfDim7,
+    Dim7,
+    MinorMajor7,
+    Aug7,
+    AugMajor7,
+    Dominant7Sus4,
+    Add9,
+    MinorAdd9,
+    Major9,
+    Minor9,
+    Dominant9,
+    Dominant11,
+    Minor11,
+    Major13,
+    Minor13,
+    Dominant13,
+    Altered,
 }
 
-static ENUM_QUALITY: Lazy<[Quality; 6]> = Lazy::new(|| {
+static ENUM_QUALITY: Lazy<[Quality; 31]> = Lazy::new(|| {
+    use Quality::*;
     [
-        Quality::Major,
-        Quality::Minor,
-        Quality::Dim,
-        Quality::Aug,
-        Quality::Major7,
-        Quality::Minor7,
+        Major,
+        Minor,
+        Dim,
+        Aug,
+        Sus2,
+        Sus4,
+        Power,
+        Major6,
+        Minor6,
+        SixNine,
+        MinorSixNine,
+        Major7,
+        Minor7,
+        Dominant7,
+        HalfDim7,
+        Dim7,
+        MinorMajor7,
+        Aug7,
+        AugMajor7,
+        Dominant7Sus4,
+        Add9,
+        MinorAdd9,
+        Major9,
+        Minor9,
+        Dominant9,
+        Dominant11,
+        Minor11,
+        Major13,
+        Minor13,
+        Dominant13,
+        Altered,
     ]
 });
 
@@ -45,49 +201,378 @@ impl Quality {
     pub fn enumerate() -> std::slice::Iter<'static, Quality> {
         ENUM_QUALITY.iter()
     }
+
+    /// Returns intervals from the root of the other chord tones, in ascending order.
+    pub fn intervals(&self) -> &'static [Interval] {
+        QUALITY_TO_INTERVAL.get(self).unwrap()
+    }
+
+    /// Returns a `Quality` whose intervals are same as given intervals.
+    /// - Returns `None` if no quality is matched.
+    pub fn from_intervals(intervals: &[Interval]) -> Option<Quality> {
+        let mut intervals = intervals.to_vec();
+        intervals.sort();
+        intervals.dedup();
+        Quality::enumerate()
+            .find(|q| q.intervals() == intervals.as_slice())
+            .copied()
+    }
 }
 
 static QUALITY_TO_INTERVAL: Lazy<HashMap<Quality, Vec<Interval>>> = Lazy::new(|| {
+    use Quality::*;
+    let intervals = |v: &[usize]| v.iter().map(|&i| i.into()).collect::<Vec<Interval>>();
     let mut hash = HashMap::<Quality, Vec<Interval>>::default();
-    hash.insert(Quality::Major, vec![4.into(), 7.into()]);
-    hash.insert(Quality::Minor, vec![3.into(), 7.into()]);
-    hash.insert(Quality::Dim, vec![3.into(), 6.into()]);
-    hash.insert(Quality::Aug, vec![4.into(), 8.into()]);
-    hash.insert(Quality::Major7, vec![4.into(), 7.into(), 11.into()]);
-    hash.insert(Quality::Minor7, vec![3.into(), 7.into(), 11.into()]);
+    hash.insert(Major, intervals(&[4, 7]));
+    hash.insert(Minor, intervals(&[3, 7]));
+    hash.insert(Dim, intervals(&[3, 6]));
+    hash.insert(Aug, intervals(&[4, 8]));
+    hash.insert(Sus2, intervals(&[2, 7]));
+    hash.insert(Sus4, intervals(&[5, 7]));
+    hash.insert(Power, intervals(&[7]));
+    hash.insert(Major6, intervals(&[4, 7, 9]));
+    hash.insert(Minor6, intervals(&[3, 7, 9]));
+    hash.insert(SixNine, intervals(&[4, 7, 9, 14]));
+    hash.insert(MinorSixNine, intervals(&[3, 7, 9, 14]));
+    hash.insert(Major7, intervals(&[4, 7, 11]));
+    hash.insert(Minor7, intervals(&[3, 7, 10]));
+    hash.insert(Dominant7, intervals(&[4, 7, 10]));
+    hash.insert(HalfDim7, intervals(&[3, 6, 10]));
+    hash.insert(Dim7, intervals(&[3, 6, 9]));
+    hash.insert(MinorMajor7, intervals(&[3, 7, 11]));
+    hash.insert(Aug7, intervals(&[4, 8, 10]));
+    hash.insert(AugMajor7, intervals(&[4, 8, 11]));
+    hash.insert(Dominant7Sus4, intervals(&[5, 7, 10]));
+    hash.insert(Add9, intervals(&[4, 7, 14]));
+    hash.insert(MinorAdd9, intervals(&[3, 7, 14]));
+    hash.insert(Major9, intervals(&[4, 7, 11, 14]));
+    hash.insert(Minor9, intervals(&[3, 7, 10, 14]));
+    hash.insert(Dominant9, intervals(&[4, 7, 10, 14]));
+    hash.insert(Dominant11, intervals(&[4, 7, 10, 14, 17]));
+    hash.insert(Minor11, intervals(&[3, 7, 10, 14, 17]));
+    hash.insert(Major13, intervals(&[4, 7, 11, 14, 21]));
+    hash.insert(Minor13, intervals(&[3, 7, 10, 14, 17, 21]));
+    hash.insert(Dominant13, intervals(&[4, 7, 10, 14, 21]));
+    hash.insert(Altered, intervals(&[4, 10, 13, 15, 18, 20]));
     hash
 });
 
 impl Chord<Tone> {
+    /// Returns an inverted chord whose bass is the `inversion`-th chord tone from the root,
+    /// like "C/E" for 1 on "C".
+    /// - `inversion` wraps around the number of chord tones.
+    /// - The bass is spelled on the letter of its degree, like "Db" of "Bbm/Db".
+    pub fn with_inversion(self, inversion: usize) -> Self {
+        let intervals = self.intervals();
+        match inversion % (intervals.len() + 1) {
+            0 => Self { bass: None, ..self },
+            i => self.with_bass(spell_above(&self.root, intervals[i - 1])),
+        }
+    }
+
+    /// Returns an index of the bass from the root among the chord tones,
+    /// which is 0 in the root position.
+    /// - Returns `None` if the bass is not a chord tone, like "C/D".
+    pub fn inversion(&self) -> Option<usize> {
+        let bass = match self.bass {
+            Some(bass) => usize::from(bass) % 12,
+            None => return Some(0),
+        };
+        let root: usize = self.root.into();
+        std::iter::once(0)
+            .chain(self.intervals().iter().map(|i| i.value()))
+            .position(|i| (root + i) % 12 == bass)
+    }
+
+    /// Returns a `core::Chord` of the chord tones.
+    /// - The bass becomes the inversion if it is a chord tone, or the slash bass otherwise.
     pub fn into_class(self) -> CoreChord<Tone> {
-        let keyboard = Keyboard::new(Twelve);
-        let intervals = QUALITY_TO_INTERVAL.get(&self.quality).unwrap();
-        let root_value: usize = self.root.into();
-        let others = intervals
-            .iter()
-            .map(|i| keyboard.get_class(&(root_value + i.value())).clone())
-            .collect();
-        CoreChord::new(self.root, others)
+        self.to_tones(&Keyboard::new(Twelve)).unwrap()
+    }
+
+    /// Returns a `core::Chord` of tones that realizes this chord on `keyboard`, as
+    /// `to_class` does.
+    /// - The root and the bass may be spelled with either accidental, like "Bb" on a
+    ///   keyboard that spells it "A#".
+    pub fn to_tones<Oct: Octave<PitchClass = Tone>>(
+        &self,
+        keyboard: &Keyboard<Oct>,
+    ) -> Option<CoreChord<Tone>> {
+        self.realize_class(keyboard, |tone| tone_number(keyboard, tone))
+    }
+
+    /// Returns a `Chord` that names `chord` of tones on `keyboard`, as `from_class` does.
+    /// - A tone may be spelled with either accidental, like "Bb" or "A#".
+    pub fn from_tones<Oct: Octave<PitchClass = Tone>>(
+        chord: &CoreChord<Tone>,
+        keyboard: &Keyboard<Oct>,
+    ) -> Option<Self> {
+        Self::name_class(chord, keyboard, |tone| tone_number(keyboard, tone))
     }
 }
 
 impl Chord<Pitch<Tone>> {
     pub fn into_pitch(self) -> CoreChord<Pitch<Tone>> {
-        let keyboard = Keyboard::new(Twelve);
-        let intervals = QUALITY_TO_INTERVAL.get(&self.quality).unwrap();
-        let root_value: usize = keyboard.as_number(&self.root).unwrap();
-        let others = intervals
+        self.realize_pitch(&Keyboard::new(Twelve), |pitch| Some(number(pitch)))
+            .unwrap()
+    }
+
+    /// Returns a `Chord` that names `chord` of pitches as `from_pitch` does on `Twelve`.
+    /// - A pitch may be spelled with either accidental, like "Bb3" or "A#3".
+    /// - Returns `None` if any pitch lies below the root, or no quality and tensions match.
+    pub fn from_pitches(chord: &CoreChord<Pitch<Tone>>) -> Option<Self> {
+        Self::name_pitch(chord, &Keyboard::new(Twelve), |pitch| Some(number(pitch)))
+    }
+}
+
+impl<T: Clone> Chord<T> {
+    /// Returns a `core::Chord` of classes that realizes this chord on `keyboard`.
+    /// - Each chord tone is the class nearest to its interval in 12 equal temperament,
+    ///   like 6 steps of 19-EDO for a major 3rd, or a scale tone on a scaled keyboard.
+    /// - The bass becomes the inversion if it is a chord tone, keeping its spelling, or the
+    ///   slash bass otherwise.
+    /// - Returns `None` if the root or the bass is not found on `keyboard`, or the
+    ///   `Octave` does not repeat.
+    pub fn to_class<Oct: Octave<PitchClass = T>>(
+        &self,
+        keyboard: &Keyboard<Oct>,
+    ) -> Option<CoreChord<T>> {
+        self.realize_class(keyboard, |class| keyboard.octave().get_number(class))
+    }
+
+    /// Returns a `Chord` that names `chord` of classes on `keyboard`.
+    /// - Each step from the root is taken as the nearest semitones in 12 equal temperament,
+    ///   and a tone within an octave is also tried as a tension above it, like "D" of "C9".
+    /// - An inversion or a slash bass becomes the bass.
+    /// - A class repeated in `chord` is taken once.
+    /// - Returns `None` if any class is not found on `keyboard`, the `Octave` does not
+    ///   repeat, or no quality and tensions match.
+    pub fn from_class<Oct: Octave<PitchClass = T>>(
+        chord: &CoreChord<T>,
+        keyboard: &Keyboard<Oct>,
+    ) -> Option<Self> {
+        Self::name_class(chord, keyboard, |class| keyboard.octave().get_number(class))
+    }
+
+    /// Returns a `core::Chord` as `to_class` does, where `number` looks up a class.
+    fn realize_class<Oct: Octave<PitchClass = T>>(
+        &self,
+        keyboard: &Keyboard<Oct>,
+        number: impl Fn(&T) -> Option<usize>,
+    ) -> Option<CoreChord<T>> {
+        let octave = keyboard.octave();
+        let root = number(&self.root)?;
+        let numbers = self.numbers(keyboard, root)?;
+        let mut others: Vec<T> = numbers
             .iter()
-            .map(|i| keyboard.get_pitch(&(root_value + i.value())).deref())
+            .map(|n| keyboard.get_class(n).clone())
             .collect();
-        CoreChord::new(self.root, others)
+        let Some(bass) = &self.bass else {
+            return Some(CoreChord::new(self.root.clone(), others));
+        };
+        let class = number(bass)?;
+        // A chord-tone bass keeps its spelling, like "Db" of "Bbm/Db".
+        if let Some(i) = numbers.iter().position(|n| n % octave.len() == class) {
+            others[i] = bass.clone();
+        }
+        let chord = CoreChord::new(self.root.clone(), others);
+        Some(with_bass_on(
+            chord,
+            root,
+            &numbers,
+            octave.len(),
+            class,
+            bass.clone(),
+        ))
+    }
+
+    /// Returns a `Chord` as `from_class` does, where `number` looks up a class.
+    fn name_class<Oct: Octave<PitchClass = T>>(
+        chord: &CoreChord<T>,
+        keyboard: &Keyboard<Oct>,
+        number: impl Fn(&T) -> Option<usize>,
+    ) -> Option<Self> {
+        let octave = keyboard.octave();
+        let root = number(chord.root())?;
+        let mut semitones = chord
+            .others()
+            .iter()
+            .map(|class| {
+                let step = (number(class)? + octave.len() - root) % octave.len();
+                semitones(keyboard, root, root + step)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        semitones.sort_unstable();
+        semitones.dedup();
+        // Only a step that a tension can take, like 2 for a 9th, is tried an octave higher.
+        let lifts: Vec<usize> = (0..semitones.len())
+            .filter(|&i| [1, 2, 3, 5, 6, 8, 9].contains(&semitones[i]))
+            .collect();
+        // The simplest chord is picked, which has the fewest tensions and lifted tones.
+        let named = (0..1_u8 << lifts.len())
+            .filter_map(|bits| {
+                let mut intervals: Vec<Interval> = semitones.iter().map(|&s| s.into()).collect();
+                for (bit, &i) in lifts.iter().enumerate() {
+                    if bits >> bit & 1 == 1 {
+                        intervals[i] = (semitones[i] + 12).into();
+                    }
+                }
+                let named = Self::from_intervals(chord.root().clone(), &intervals)?;
+                Some(((named.tensions.len(), bits.count_ones()), named))
+            })
+            .min_by_key(|(cost, _)| *cost)?
+            .1;
+        Some(named.with_lowest(chord))
+    }
+
+    /// Returns numbers of the other chord tones on `keyboard` from the number of the root.
+    fn numbers<Oct: Octave>(&self, keyboard: &Keyboard<Oct>, root: usize) -> Option<Vec<usize>> {
+        self.intervals()
+            .iter()
+            .map(|i| keyboard.nearest(root, 2_f64.powf(i.value() as f64 / 12.0)))
+            .collect()
+    }
+
+    /// Returns this chord whose bass is the lowest tone of `chord` if it is not the root.
+    fn with_lowest(self, chord: &CoreChord<T>) -> Self {
+        match (chord.bass(), chord.inversion()) {
+            (Some(bass), _) => self.with_bass(bass.clone()),
+            (None, 0) => self,
+            (None, _) => self.with_bass(chord.lowest().clone()),
+        }
+    }
+}
+
+impl<T: Clone> Chord<Pitch<T>> {
+    /// Returns a `core::Chord` of pitches that realizes this chord on `keyboard`, from the root.
+    /// - Each chord tone is the pitch nearest to its interval in 12 equal temperament.
+    /// - The bass becomes the inversion if it is a chord tone, or the slash bass otherwise,
+    ///   as `to_class` does. The period of a chord-tone bass is then that of the chord tone.
+    /// - Returns `None` if the root is not found on `keyboard`, or the `Octave` does not
+    ///   repeat.
+    pub fn to_pitch<Oct: Octave<PitchClass = T>>(
+        &self,
+        keyboard: &Keyboard<Oct>,
+    ) -> Option<CoreChord<Pitch<T>>> {
+        self.realize_pitch(keyboard, |pitch| keyboard.as_number(pitch))
+    }
+
+    /// Returns a `Chord` that names `chord` of pitches on `keyboard`.
+    /// - Each distance from the root is taken as the nearest semitones in 12 equal
+    ///   temperament.
+    /// - An inversion or a slash bass becomes the bass.
+    /// - Returns `None` if any pitch is not found on `keyboard` or lies below the root,
+    ///   the `Octave` does not repeat, or no quality and tensions match.
+    pub fn from_pitch<Oct: Octave<PitchClass = T>>(
+        chord: &CoreChord<Pitch<T>>,
+        keyboard: &Keyboard<Oct>,
+    ) -> Option<Self> {
+        Self::name_pitch(chord, keyboard, |pitch| keyboard.as_number(pitch))
+    }
+
+    /// Returns a `core::Chord` as `to_pitch` does, where `number` looks up a pitch.
+    fn realize_pitch<Oct: Octave<PitchClass = T>>(
+        &self,
+        keyboard: &Keyboard<Oct>,
+        number: impl Fn(&Pitch<T>) -> Option<usize>,
+    ) -> Option<CoreChord<Pitch<T>>> {
+        let root = number(&self.root)?;
+        let numbers = self.numbers(keyboard, root)?;
+        let others = numbers.iter().map(|n| keyboard.get_pitch(n).deref());
+        let chord = CoreChord::new(self.root.clone(), others.collect());
+        let Some(bass) = &self.bass else {
+            return Some(chord);
+        };
+        let len = keyboard.octave().len();
+        let class = number(bass)? % len;
+        Some(with_bass_on(
+            chord,
+            root,
+            &numbers,
+            len,
+            class,
+            bass.clone(),
+        ))
+    }
+
+    /// Returns a `Chord` as `from_pitch` does, where `number` looks up a pitch.
+    fn name_pitch<Oct: Octave<PitchClass = T>>(
+        chord: &CoreChord<Pitch<T>>,
+        keyboard: &Keyboard<Oct>,
+        number: impl Fn(&Pitch<T>) -> Option<usize>,
+    ) -> Option<Self> {
+        let root = number(chord.root())?;
+        let intervals = chord
+            .others()
+            .iter()
+            .map(|pitch| {
+                let to = number(pitch).filter(|n| *n >= root)?;
+                semitones(keyboard, root, to).map(Interval::from)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let named = Self::from_intervals(chord.root().clone(), &intervals)?;
+        Some(named.with_lowest(chord))
     }
 }
 
+/// Returns `chord` inverted on `bass` if its class is a chord tone, or with the slash bass
+/// otherwise.
+/// - `root` and `numbers` are numbers of the chord tones on an `Octave` of `len` classes.
+fn with_bass_on<X>(
+    chord: CoreChord<X>,
+    root: usize,
+    numbers: &[usize],
+    len: usize,
+    class: usize,
+    bass: X,
+) -> CoreChord<X> {
+    let inversion = std::iter::once(&root)
+        .chain(numbers)
+        .position(|n| n % len == class);
+    match inversion {
+        Some(inversion) => chord.with_inversion(inversion),
+        None => chord.with_bass(bass),
+    }
+}
+
+/// Returns the tone `interval` above `root`, spelled on the letter of its degree like "Db"
+/// for a minor 3rd above "Bb".
+/// - A tone that needs a double accidental is spelled with the accidental of `root`.
+pub(crate) fn spell_above(root: &Tone, interval: Interval) -> Tone {
+    use ToneSymbol::*;
+    let letters = [C, D, E, F, G, A, B];
+    let degree = *ChordFormula::new(&[interval]).degrees().last().unwrap();
+    let from = letters.iter().position(|l| l == root.tone()).unwrap();
+    let letter = letters[(from + Number::value(&degree.degree())) % 7];
+    let class = (usize::from(*root) + interval.value()) % 12;
+    match (class + 12 - letter as usize) % 12 {
+        0 => Tone::new(letter, AccidentalSymbol::Natural),
+        1 => Tone::new(letter, AccidentalSymbol::Sharp),
+        11 => Tone::new(letter, AccidentalSymbol::Flat),
+        _ => Tone::spell(class, *root.accidental()),
+    }
+}
+
+/// Returns a number of the class of `tone` on `keyboard`, which may be spelled with either
+/// accidental.
+fn tone_number<Oct: Octave<PitchClass = Tone>>(
+    keyboard: &Keyboard<Oct>,
+    tone: &Tone,
+) -> Option<usize> {
+    keyboard.class_iter().position(|c| class(c) == class(tone))
+}
+
+/// Returns semitones in 12 equal temperament nearest to a distance of numbers on `keyboard`.
+fn semitones<Oct: Octave>(keyboard: &Keyboard<Oct>, from: usize, to: usize) -> Option<usize> {
+    let ratio = keyboard.ratio(&to)? / keyboard.ratio(&from)?;
+    Some((12.0 * ratio.log2()).round() as usize)
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::prelude::{Diatonic, Edo, Figure, Keyboard, Pitch, Scaled, Tension, Tone, Twelve};
 
-    use super::Chord;
+    use super::{AccidentalSymbol::Flat, Chord, CoreChord, Quality, ToneSymbol::C};
 
     #[test]
     fn into_class() {
@@ -100,4 +585,150 @@ mod tests {
         assert_eq!(chord.next(), Some("E".parse().unwrap()));
         assert_eq!(chord.next(), Some("Gs".parse().unwrap()));
     }
+
+    #[test]
+    fn minor7() {
+        let chord = Chord::new("D".parse().unwrap(), Quality::Minor7).into_class();
+        let tones: Vec<Tone> = ["D", "F", "A", "C"].map(|t| t.parse().unwrap()).to_vec();
+        assert_eq!(chord.into_vec(), tones);
+    }
+
+    #[test]
+    fn intervals() {
+        let chord = Chord::new("G".parse::<Tone>().unwrap(), Quality::Dominant9)
+            .with_tension(Tension::FlatNine)
+            .with_tension(Tension::SharpEleven);
+        let intervals: Vec<usize> = chord.intervals().iter().map(|i| i.value()).collect();
+        assert_eq!(intervals, vec![4, 7, 10, 13, 18]);
+        let chord = Chord::new("C".parse::<Tone>().unwrap(), Quality::Dominant7)
+            .with_tension(Tension::SharpFive);
+        let intervals: Vec<usize> = chord.intervals().iter().map(|i| i.value()).collect();
+        assert_eq!(intervals, vec![4, 8, 10]);
+    }
+
+    #[test]
+    fn into_pitch() {
+        let root = Pitch::new("C".parse().unwrap(), 4);
+        let chord = Chord::new(root, Quality::Dominant13).into_pitch();
+        let pitches = ["C4", "E4", "G4", "As4", "D5", "A5"]
+            .map(|p| {
+                Pitch::new(
+                    p[..p.len() - 1].parse().unwrap(),
+                    p[p.len() - 1..].parse().unwrap(),
+                )
+            })
+            .to_vec();
+        assert_eq!(chord.into_vec(), pitches);
+        let pitch = |t: &str, o| Pitch::new(t.parse::<Tone>().unwrap(), o);
+        let chord = Chord::new(pitch("C", 4), Quality::Major).with_bass(pitch("E", 3));
+        let class: Chord<Tone> = "C/E".parse().unwrap();
+        assert_eq!(
+            chord.into_pitch().inversion(),
+            class.into_class().inversion()
+        );
+        let chord = Chord::new(pitch("C", 4), Quality::Major).with_bass(pitch("D", 3));
+        assert_eq!(chord.into_pitch().bass(), Some(&pitch("D", 3)));
+        let root = Pitch::new(Tone::written(C, Flat), 4);
+        let chord = Chord::new(root, Quality::Major).into_pitch();
+        assert_eq!(chord.others(), &[pitch("D#", 4), pitch("F#", 4)]);
+    }
+
+    #[test]
+    fn inversion() {
+        let chord: Chord<Tone> = "C7/Bb".parse().unwrap();
+        assert_eq!(chord.inversion(), Some(3));
+        let chord = chord.into_class();
+        assert_eq!(chord.figure(), Some(Figure::FourTwo));
+        let chord: Chord<Tone> = "Dm".parse().unwrap();
+        assert_eq!(chord.with_inversion(1), "Dm/F".parse().unwrap());
+        assert_eq!(chord.with_inversion(3), chord);
+        let chord: Chord<Tone> = "Bbm".parse().unwrap();
+        assert_eq!(chord.with_inversion(1), "Bbm/Db".parse().unwrap());
+        let chord: Chord<Tone> = "E7".parse().unwrap();
+        assert_eq!(chord.with_inversion(1), "E7/G#".parse().unwrap());
+        let chord: Chord<Tone> = "C/D".parse().unwrap();
+        assert_eq!(chord.inversion(), None);
+        assert_eq!(chord.into_class().bass(), Some(&"D".parse().unwrap()));
+    }
+
+    #[test]
+    fn from_intervals() {
+        let intervals = |v: &[usize]| v.iter().map(|&i| i.into()).collect::<Vec<_>>();
+        assert_eq!(
+            Quality::from_intervals(&intervals(&[7, 4, 10])),
+            Some(Quality::Dominant7)
+        );
+        assert_eq!(Quality::from_intervals(&intervals(&[4, 6])), None);
+        let chord =
+            Chord::from_intervals("E".parse::<Tone>().unwrap(), &intervals(&[3, 7, 10, 13]));
+        assert_eq!(chord, Some("Em7(b9)".parse().unwrap()));
+        let chord = Chord::from_intervals("C".parse::<Tone>().unwrap(), &intervals(&[4, 6]));
+        assert_eq!(chord, Some("C(b5)".parse().unwrap()));
+        assert_eq!(Chord::from_intervals((), &intervals(&[5, 11])), None);
+    }
+
+    #[test]
+    fn edo() {
+        let keyboard = Keyboard::new(Edo::new(19));
+        let chord = Chord::new(2_usize, Quality::Dominant7).with_bass(8);
+        let core = chord.to_class(&keyboard).unwrap();
+        assert_eq!(core.clone().into_vec(), vec![2, 8, 13, 18]);
+        assert_eq!(core.inversion(), 1);
+        assert_eq!(Chord::from_class(&core, &keyboard), Some(chord));
+
+        let root = Pitch::new(0_usize, 1);
+        let chord = Chord::new(root, Quality::Minor9);
+        let core = chord.to_pitch(&keyboard).unwrap();
+        assert_eq!(core.others()[3], Pitch::new(3, 2));
+        assert_eq!(Chord::from_pitch(&core, &keyboard), Some(chord));
+
+        let keyboard = Keyboard::new(Edo::new(72));
+        let classes = (0..40).map(|n| n * 36 / 40 + 1).collect();
+        let chord = CoreChord::new(0, classes);
+        assert_eq!(Chord::from_class(&chord, &keyboard), None);
+    }
+
+    #[test]
+    fn scaled() {
+        let scale = Diatonic::major(&"C".parse().unwrap());
+        let keyboard = Keyboard::new(Scaled::new(scale, Twelve));
+        let chord = Chord::new("D".parse::<Tone>().unwrap(), Quality::Minor7);
+        let tones: Vec<Tone> = ["D", "F", "A", "C"].map(|t| t.parse().unwrap()).to_vec();
+        assert_eq!(chord.to_class(&keyboard).unwrap().into_vec(), tones);
+        let scale = Diatonic::major(&"F".parse().unwrap());
+        let keyboard = Keyboard::new(Scaled::new(scale, Twelve));
+        let chord: Chord<Tone> = "Bb".parse().unwrap();
+        assert_eq!(chord.to_class(&keyboard), None);
+        let core = chord.to_tones(&keyboard).unwrap();
+        let tones: Vec<Tone> = ["Bb", "D", "F"].map(|t| t.parse().unwrap()).to_vec();
+        assert_eq!(core.clone().into_vec(), tones);
+        assert_eq!(Chord::from_tones(&core, &keyboard), Some(chord));
+    }
+
+    #[test]
+    fn round_trip() {
+        for symbol in [
+            "C",
+            "Ebm7",
+            "G9",
+            "F#7(#9)",
+            "Bbm(add9)",
+            "C6/E",
+            "Am/D",
+            "Db13",
+            "Bbm/Db",
+            "Ebm7/Gb",
+            "Gb/Bb",
+        ] {
+            let chord: Chord<Tone> = symbol.parse().unwrap();
+            let named = Chord::from_tones(&chord.into_class(), &Keyboard::new(Twelve)).unwrap();
+            assert_eq!(named, chord, "{symbol}");
+        }
+        let pitch = |t: &str, o| Pitch::new(t.parse::<Tone>().unwrap(), o);
+        let chord = Chord::new(pitch("Bb", 3), Quality::Minor7).with_tension(Tension::FlatNine);
+        let named = Chord::from_pitches(&chord.into_pitch()).unwrap();
+        assert_eq!(named, chord);
+        let chord: Chord<Tone> = "Bb".parse().unwrap();
+        assert_eq!(chord.to_class(&Keyboard::new(Twelve)), None);
+    }
 }
@@ -0,0 +1,195 @@
+use std::fmt::Display;
+
+use crate::{
+    core::Chord as CoreChord,
+    prelude::{AlteredDegree, Chord, Degree, Interval, Keyboard, Number, Octave},
+};
+
+/// Semitones of each degree of the major scale from the 1st.
+const MAJOR: [usize; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// A chord built from intervals of its tones, like "1 b3 5 b7 11" or "P1 M3 A5",
+/// which needs no `Quality`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChordFormula {
+    intervals: Vec<Interval>,
+    /// Degrees of `intervals` as written, like "#4" rather than "b5".
+    spelling: Option<Vec<AlteredDegree>>,
+}
+
+impl ChordFormula {
+    /// Returns a `ChordFormula` of the other chord tones, which are semitones from the root.
+    /// - The root itself and duplicates are removed, and the rest are sorted.
+    pub fn new(intervals: &[Interval]) -> Self {
+        let mut intervals: Vec<Interval> = intervals
+            .iter()
+            .filter(|i| i.value() != 0)
+            .copied()
+            .collect();
+        intervals.sort();
+        intervals.dedup();
+        Self {
+            intervals,
+            spelling: None,
+        }
+    }
+
+    /// Returns a `ChordFormula` of altered degrees, which are relative to the major scale
+    /// on the root, like "b3" for a minor 3rd.
+    /// - The degrees are kept as written, and the first of a repeated interval is taken.
+    /// - Returns `None` if any degree falls below the root.
+    pub fn from_degrees(degrees: &[AlteredDegree]) -> Option<Self> {
+        let mut tones = degrees
+            .iter()
+            .map(|d| {
+                let number = Number::value(&d.degree());
+                let semitones = MAJOR[number % 7] + 12 * (number / 7);
+                let semitones = semitones as i32 + d.alteration();
+                (semitones >= 0).then(|| (Interval::from(semitones as usize), *d))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        tones.retain(|(i, _)| i.value() != 0);
+        tones.sort_by_key(|(i, _)| *i);
+        tones.dedup_by_key(|(i, _)| *i);
+        Some(Self {
+            intervals: tones.iter().map(|(i, _)| *i).collect(),
+            spelling: Some(tones.into_iter().map(|(_, d)| d).collect()),
+        })
+    }
+
+    /// Returns intervals from the root of the other chord tones, in ascending order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Returns altered degrees of the chord tones from the root, like "b7" and "#11".
+    /// - Degrees are as written if this formula is from degrees, like "#4" of "1 3 #4".
+    pub fn degrees(&self) -> Vec<AlteredDegree> {
+        let others = match &self.spelling {
+            Some(spelling) => spelling.clone(),
+            None => self.intervals.iter().map(|i| degree(i.value())).collect(),
+        };
+        std::iter::once(degree(0)).chain(others).collect()
+    }
+
+    /// Returns a named `Chord` on `root` if a quality and tensions match this formula.
+    pub fn name<T>(&self, root: T) -> Option<Chord<T>> {
+        Chord::from_intervals(root, &self.intervals)
+    }
+
+    /// Returns a `core::Chord` of classes on `root` that realizes this formula on `keyboard`.
+    /// - Each chord tone is the class nearest to its interval in 12 equal temperament.
+    /// - Returns `None` if `root` is not found on `keyboard`, or the `Octave` does not
+    ///   repeat.
+    pub fn to_class<Oct: Octave>(
+        &self,
+        root: &Oct::PitchClass,
+        keyboard: &Keyboard<Oct>,
+    ) -> Option<CoreChord<Oct::PitchClass>>
+    where
+        Oct::PitchClass: Clone,
+    {
+        let number = keyboard.octave().get_number(root)?;
+        let others = self
+            .intervals
+            .iter()
+            .map(|i| {
+                let ratio = 2_f64.powf(i.value() as f64 / 12.0);
+                let other = keyboard.nearest(number, ratio)?;
+                Some(keyboard.get_class(&other).clone())
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(CoreChord::new(root.clone(), others))
+    }
+}
+
+impl<T> From<&Chord<T>> for ChordFormula {
+    fn from(value: &Chord<T>) -> Self {
+        Self::new(&value.intervals())
+    }
+}
+
+/// Returns an altered degree of a tone `semitones` above the root.
+fn degree(semitones: usize) -> AlteredDegree {
+    let (number, alteration) = match semitones % 12 {
+        1 => (1, -1),
+        3 if semitones > 12 => (1, 1),
+        3 => (2, -1),
+        6 if semitones > 12 => (3, 1),
+        6 => (4, -1),
+        8 if semitones > 12 => (5, -1),
+        8 => (4, 1),
+        10 => (6, -1),
+        r => (MAJOR.iter().position(|d| *d == r).unwrap(), 0),
+    };
+    // A tone above an octave is an extension, like "9" rather than "2".
+    let number = number + 7 * (semitones / 12);
+    AlteredDegree::new(Degree::new(number + 1).unwrap(), alteration)
+}
+
+/// Writes the formula as degrees, like "1 3 5 b7 9".
+impl Display for ChordFormula {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let degrees: Vec<String> = self
+            .degrees()
+            .iter()
+            .map(|d| {
+                let accidental = if d.alteration() < 0 { "b" } else { "#" };
+                let accidentals = accidental.repeat(d.alteration().unsigned_abs() as usize);
+                format!("{accidentals}{}", d.degree().value())
+            })
+            .collect();
+        write!(f, "{}", degrees.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn formula(s: &str) -> ChordFormula {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn name() {
+        let root: Tone = "C".parse().unwrap();
+        assert_eq!(
+            formula("1 b3 5 b7 11").name(root),
+            Some("Cm7(11)".parse().unwrap())
+        );
+        assert_eq!(formula("P1 M3 A5").name(root), Some("C+".parse().unwrap()));
+        assert_eq!(
+            formula("1 3 5 b7 9").name(root),
+            Some("C9".parse().unwrap())
+        );
+        assert_eq!(formula("1 2 4").name(root), None);
+    }
+
+    #[test]
+    fn degrees() {
+        assert_eq!(formula("1 b3 5 b7 11").to_string(), "1 b3 5 b7 11");
+        assert_eq!(formula("P1 M3 d5 m7 m9").to_string(), "1 3 b5 b7 b9");
+        let chord: Chord<Tone> = "G7(#9,b13)".parse().unwrap();
+        assert_eq!(ChordFormula::from(&chord).to_string(), "1 3 5 b7 #9 b13");
+        assert_eq!(formula("1 3 #4").to_string(), "1 3 #4");
+        assert_eq!(formula("1 b3 bb7").to_string(), "1 b3 bb7");
+        assert_eq!(formula("1 5 3 3").to_string(), "1 3 5");
+        assert_eq!(
+            formula("1 b3 bb7").intervals(),
+            formula("1 b3 6").intervals()
+        );
+    }
+
+    #[test]
+    fn to_class() {
+        let keyboard = Keyboard::new(Twelve);
+        let root = "D".parse().unwrap();
+        let chord = formula("1 4 5 b9").to_class(&root, &keyboard).unwrap();
+        let tones: Vec<Tone> = ["D", "G", "A", "Ds"].map(|t| t.parse().unwrap()).to_vec();
+        assert_eq!(chord.into_vec(), tones);
+        let keyboard = Keyboard::new(Edo::new(31));
+        let chord = formula("1 3 5").to_class(&0, &keyboard).unwrap();
+        assert_eq!(chord.into_vec(), vec![0, 10, 18]);
+    }
+}
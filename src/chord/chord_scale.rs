@@ -0,0 +1,143 @@
+use crate::prelude::{
+    AccidentalSymbol, Chord, Diatonic, Formula, KeySignature, ScaleKind, Tension, Tensions, Tone,
+};
+
+/// A scale that sounds with a chord, like the mixolydian on "G7".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordScale {
+    kind: ScaleKind,
+    scale: Formula,
+    tensions: Tensions,
+    avoid: Vec<Tone>,
+}
+
+impl ChordScale {
+    pub fn kind(&self) -> ScaleKind {
+        self.kind
+    }
+
+    /// Returns the scale from the root of the chord.
+    pub fn scale(&self) -> &Formula {
+        &self.scale
+    }
+
+    /// Returns tensions of the scale that can be added to the chord.
+    pub fn tensions(&self) -> Tensions {
+        self.tensions
+    }
+
+    /// Returns tones of the scale a half step above a chord tone, like "C" on "G7".
+    pub fn avoid_notes(&self) -> &[Tone] {
+        &self.avoid
+    }
+
+    /// Returns a `ChordScale` of `kind` on the root of `chord`.
+    /// - Returns `None` if the scale lacks any tone of `chord`, including its tensions.
+    pub fn new(chord: &Chord<Tone>, kind: ScaleKind) -> Option<Self> {
+        let root = usize::from(*chord.root());
+        let mut classes: Vec<usize> = chord.intervals().iter().map(|i| i.value() % 12).collect();
+        classes.push(0);
+        let distances = kind.distances();
+        if !classes.iter().all(|c| distances.contains(c)) {
+            return None;
+        }
+        let scale = Formula::named(chord.root(), kind);
+        let accidental = match chord.root().accidental() {
+            AccidentalSymbol::Natural => KeySignature::from_mode(&scale)
+                .map(|s| s.accidental())
+                .unwrap_or(AccidentalSymbol::Sharp),
+            accidental => *accidental,
+        };
+
+        let mut tensions = Tensions::new();
+        let mut avoid = vec![];
+        for distance in distances.iter().filter(|d| !classes.contains(d)) {
+            if classes.contains(&((distance + 11) % 12)) {
+                avoid.push(Tone::spell((root + distance) % 12, accidental));
+                continue;
+            }
+            // An extension over the fifth is preferred to an alteration of it, like
+            // "#11" to "b5".
+            let tension = Tension::enumerate()
+                .filter(|t| t.interval().value() % 12 == *distance)
+                .min_by_key(|t| t.replaces().is_some_and(|r| r.value() == 7));
+            if let Some(tension) = tension {
+                tensions.insert(*tension);
+            }
+        }
+        Some(Self {
+            kind,
+            scale,
+            tensions,
+            avoid,
+        })
+    }
+}
+
+/// Returns scales that sound with `chord` alone, from the most common.
+/// - Scales with 6 tones or more are tried, except the chromatic scale.
+pub fn chord_scales(chord: &Chord<Tone>) -> Vec<ChordScale> {
+    ScaleKind::enumerate()
+        .filter(|k| k.distances().len() >= 6 && **k != ScaleKind::Chromatic)
+        .filter_map(|k| ChordScale::new(chord, *k))
+        .collect()
+}
+
+/// Returns scales that sound with `chord` in `key`, from the one with the fewest tones
+/// out of the key, like the mixolydian on "G7" in C major.
+pub fn chord_scales_in(chord: &Chord<Tone>, key: &Diatonic) -> Vec<ChordScale> {
+    let key = Formula::from(key);
+    let tonic = usize::from(*key.key());
+    let outside = |scale: &ChordScale| {
+        let root = usize::from(*scale.scale.key());
+        scale
+            .scale
+            .distances()
+            .iter()
+            .filter(|d| !key.distances().contains(&((root + *d + 12 - tonic) % 12)))
+            .count()
+    };
+    let mut scales = chord_scales(chord);
+    // The sort is stable, so the most common scale comes first among ties.
+    scales.sort_by_key(outside);
+    scales
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn chord(symbol: &str) -> Chord<Tone> {
+        symbol.parse().unwrap()
+    }
+
+    #[test]
+    fn in_key() {
+        let key = Diatonic::major(&"C".parse().unwrap());
+        let scale = chord_scales_in(&chord("G7"), &key).remove(0);
+        assert_eq!(scale.kind(), ScaleKind::Mixolydian);
+        assert!(scale.tensions().contains(Tension::Thirteen));
+        assert!(scale.tensions().contains(Tension::Nine));
+        assert_eq!(scale.avoid_notes(), &["C".parse().unwrap()]);
+
+        let scale = chord_scales_in(&chord("Fmaj7"), &key).remove(0);
+        assert_eq!(scale.kind(), ScaleKind::Lydian);
+        assert!(scale.avoid_notes().is_empty());
+        assert!(scale.tensions().contains(Tension::SharpEleven));
+    }
+
+    #[test]
+    fn alone() {
+        let kinds: Vec<ScaleKind> = chord_scales(&chord("G7(b9,#5)"))
+            .iter()
+            .map(|s| s.kind())
+            .collect();
+        assert!(kinds.contains(&ScaleKind::Altered));
+        assert!(!kinds.contains(&ScaleKind::HalfWholeDiminished));
+        assert!(!kinds.contains(&ScaleKind::Mixolydian));
+
+        let scale = ChordScale::new(&chord("Bm7b5"), ScaleKind::Locrian).unwrap();
+        assert_eq!(scale.avoid_notes(), &["C".parse().unwrap()]);
+        assert!(ChordScale::new(&chord("Cmaj7"), ScaleKind::Mixolydian).is_none());
+    }
+}
@@ -0,0 +1,325 @@
+use std::fmt::Display;
+
+use crate::prelude::{AccidentalSymbol, Chord, Quality, Tension, Tone, ToneSymbol};
+
+/// Conventions of writing chord symbols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Style {
+    /// Like "C-7", "C∆" and "Cø".
+    Jazz,
+    /// Like "Cm7", "Cmaj7" and "Cm7b5".
+    #[default]
+    Pop,
+    /// Like "Cmin7", "Cmaj7" and "Cø7".
+    Classical,
+    /// Like "Cmi7", "Cmaj7" and "Cmi7(b5)", with tensions separated by ", ".
+    Berklee,
+    /// Like "C-7", "C∆7" and "Cø7".
+    RealBook,
+}
+
+/// An order of writing tensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TensionOrder {
+    /// From the lowest, like "(b9,#11)".
+    #[default]
+    Ascending,
+    /// From the highest, like "(#11,b9)".
+    Descending,
+}
+
+/// Renders `Chord`s to chord symbols that `Chord<Tone>::from_str` can read back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Formatter {
+    style: Style,
+    unicode: bool,
+    order: TensionOrder,
+}
+
+impl Formatter {
+    pub fn new(style: Style) -> Self {
+        Self {
+            style,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a formatter that uses glyphs like "♭", "♯", "∆", "°" and "ø".
+    pub fn with_unicode(self, unicode: bool) -> Self {
+        Self { unicode, ..self }
+    }
+
+    pub fn with_tension_order(self, order: TensionOrder) -> Self {
+        Self { order, ..self }
+    }
+
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    pub fn format(&self, chord: &Chord<Tone>) -> String {
+        let mut symbol = self.tone(chord.root());
+        symbol.push_str(self.quality(chord.quality()));
+        let mut tensions: Vec<String> = chord.tensions().iter().map(|t| self.tension(t)).collect();
+        if self.order == TensionOrder::Descending {
+            tensions.reverse();
+        }
+        if !tensions.is_empty() {
+            // Unaltered tensions like "9" and "13" would run together without a separator.
+            let separator = match self.style {
+                Style::Berklee => ", ",
+                _ => ",",
+            };
+            symbol.push_str(&format!("({})", tensions.join(separator)));
+        }
+        if let Some(bass) = chord.bass() {
+            symbol.push('/');
+            symbol.push_str(&self.tone(bass));
+        }
+        symbol
+    }
+
+    fn tone(&self, tone: &Tone) -> String {
+        let letter = match tone.tone() {
+            ToneSymbol::C => 'C',
+            ToneSymbol::D => 'D',
+            ToneSymbol::E => 'E',
+            ToneSymbol::F => 'F',
+            ToneSymbol::G => 'G',
+            ToneSymbol::A => 'A',
+            ToneSymbol::B => 'B',
+        };
+        format!("{letter}{}", self.accidental(*tone.accidental()))
+    }
+
+    fn accidental(&self, accidental: AccidentalSymbol) -> &'static str {
+        match (accidental, self.unicode) {
+            (AccidentalSymbol::Flat, true) => "♭",
+            (AccidentalSymbol::Flat, false) => "b",
+            (AccidentalSymbol::Sharp, true) => "♯",
+            (AccidentalSymbol::Sharp, false) => "#",
+            (AccidentalSymbol::Natural, _) => "",
+        }
+    }
+
+    fn tension(&self, tension: Tension) -> String {
+        use AccidentalSymbol::*;
+        use Tension::*;
+        let (accidental, number) = match tension {
+            FlatFive => (Flat, 5),
+            SharpFive => (Sharp, 5),
+            FlatNine => (Flat, 9),
+            Nine => (Natural, 9),
+            SharpNine => (Sharp, 9),
+            Eleven => (Natural, 11),
+            SharpEleven => (Sharp, 11),
+            FlatThirteen => (Flat, 13),
+            Thirteen => (Natural, 13),
+        };
+        format!("{}{number}", self.accidental(accidental))
+    }
+
+    fn quality(&self, quality: Quality) -> &'static str {
+        use Quality::*;
+        use Style::*;
+        let unicode = self.unicode;
+        match (self.style, quality) {
+            (_, Sus2) => "sus2",
+            (Jazz | RealBook, Dominant7Sus4) => "7sus",
+            (_, Dominant7Sus4) => "7sus4",
+            (_, Power) => "5",
+            (_, Dominant7) => "7",
+            (_, Dominant9) => "9",
+            (_, Dominant11) => "11",
+            (_, Dominant13) => "13",
+            (_, Altered) => "7alt",
+
+            (Classical, Major) => "maj",
+            (_, Major) => "",
+            (Jazz | RealBook, Minor) => "-",
+            (Pop, Minor) => "m",
+            (Classical, Minor) => "min",
+            (Berklee, Minor) => "mi",
+            (Jazz | RealBook, Dim) if unicode => "°",
+            (Jazz | RealBook | Berklee, Dim) => "o",
+            (_, Dim) => "dim",
+            (Jazz | RealBook | Berklee, Aug) => "+",
+            (_, Aug) => "aug",
+            (Pop | Classical, Sus4) => "sus4",
+            (_, Sus4) => "sus",
+
+            (Classical, Major6) => "maj6",
+            (_, Major6) => "6",
+            (Jazz | RealBook, Minor6) => "-6",
+            (Pop, Minor6) => "m6",
+            (Classical, Minor6) => "min6",
+            (Berklee, Minor6) => "mi6",
+            (_, SixNine) => "6/9",
+            (Jazz | RealBook, MinorSixNine) => "-6/9",
+            (Pop, MinorSixNine) => "m6/9",
+            (Classical, MinorSixNine) => "min6/9",
+            (Berklee, MinorSixNine) => "mi6/9",
+
+            (Jazz, Major7) if unicode => "∆",
+            (Jazz, Major7) => "^",
+            (RealBook, Major7) if unicode => "∆7",
+            (RealBook, Major7) => "^7",
+            (_, Major7) => "maj7",
+            (Jazz | RealBook, Minor7) => "-7",
+            (Pop, Minor7) => "m7",
+            (Classical, Minor7) => "min7",
+            (Berklee, Minor7) => "mi7",
+            (Jazz, HalfDim7) if unicode => "ø",
+            (RealBook | Classical, HalfDim7) if unicode => "ø7",
+            (Jazz | RealBook, HalfDim7) => "-7b5",
+            (Pop, HalfDim7) if unicode => "m7♭5",
+            (Pop, HalfDim7) => "m7b5",
+            (Classical, HalfDim7) => "min7b5",
+            (Berklee, HalfDim7) if unicode => "mi7(♭5)",
+            (Berklee, HalfDim7) => "mi7(b5)",
+            (Jazz | RealBook, Dim7) if unicode => "°7",
+            (Jazz | RealBook | Berklee, Dim7) => "o7",
+            (_, Dim7) => "dim7",
+            (Jazz | RealBook, MinorMajor7) if unicode => "-∆7",
+            (Jazz | RealBook, MinorMajor7) => "-^7",
+            (Pop, MinorMajor7) => "mMaj7",
+            (Classical, MinorMajor7) => "min(maj7)",
+            (Berklee, MinorMajor7) => "mi(maj7)",
+            (Jazz | RealBook | Berklee, Aug7) => "+7",
+            (Pop, Aug7) if unicode => "7♯5",
+            (Pop, Aug7) => "7#5",
+            (Classical, Aug7) => "aug7",
+            (Jazz | RealBook, AugMajor7) if unicode => "+∆7",
+            (Jazz | RealBook | Berklee, AugMajor7) => "+maj7",
+            (Pop, AugMajor7) if unicode => "maj7♯5",
+            (Pop, AugMajor7) => "maj7#5",
+            (Classical, AugMajor7) => "augmaj7",
+
+            (Berklee, Add9) => "(add9)",
+            (_, Add9) => "add9",
+            (Jazz | RealBook, MinorAdd9) => "-add9",
+            (Pop, MinorAdd9) => "madd9",
+            (Classical, MinorAdd9) => "minadd9",
+            (Berklee, MinorAdd9) => "mi(add9)",
+            (Jazz | RealBook, Major9) if unicode => "∆9",
+            (Jazz | RealBook, Major9) => "^9",
+            (_, Major9) => "maj9",
+            (Jazz | RealBook, Minor9) => "-9",
+            (Pop, Minor9) => "m9",
+            (Classical, Minor9) => "min9",
+            (Berklee, Minor9) => "mi9",
+            (Jazz | RealBook, Minor11) => "-11",
+            (Pop, Minor11) => "m11",
+            (Classical, Minor11) => "min11",
+            (Berklee, Minor11) => "mi11",
+            (Jazz | RealBook, Major13) if unicode => "∆13",
+            (Jazz | RealBook, Major13) => "^13",
+            (_, Major13) => "maj13",
+            (Jazz | RealBook, Minor13) => "-13",
+            (Pop, Minor13) => "m13",
+            (Classical, Minor13) => "min13",
+            (Berklee, Minor13) => "mi13",
+        }
+    }
+}
+
+/// Writes a chord symbol in the pop style, like "Cm7(b9)/G".
+impl Display for Chord<Tone> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Formatter::default().format(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    const STYLES: [Style; 5] = [
+        Style::Jazz,
+        Style::Pop,
+        Style::Classical,
+        Style::Berklee,
+        Style::RealBook,
+    ];
+
+    fn chord(symbol: &str) -> Chord<Tone> {
+        symbol.parse().unwrap()
+    }
+
+    #[test]
+    fn styles() {
+        let minor = chord("C-7");
+        let major = chord("Cmaj7");
+        let half = chord("Cm7b5");
+        let expected = [
+            ("C-7", "C^", "C-7b5"),
+            ("Cm7", "Cmaj7", "Cm7b5"),
+            ("Cmin7", "Cmaj7", "Cmin7b5"),
+            ("Cmi7", "Cmaj7", "Cmi7(b5)"),
+            ("C-7", "C^7", "C-7b5"),
+        ];
+        for (style, expected) in STYLES.into_iter().zip(expected) {
+            let formatter = Formatter::new(style);
+            let actual = (
+                formatter.format(&minor),
+                formatter.format(&major),
+                formatter.format(&half),
+            );
+            assert_eq!(
+                (actual.0.as_str(), actual.1.as_str(), actual.2.as_str()),
+                expected
+            );
+        }
+        let jazz = Formatter::new(Style::Jazz).with_unicode(true);
+        assert_eq!(jazz.format(&major), "C∆");
+        assert_eq!(jazz.format(&half), "Cø");
+        assert_eq!(jazz.format(&chord("Bb°7")), "B♭°7");
+    }
+
+    #[test]
+    fn tensions() {
+        let g7 = chord("G7(b9,#11)/B");
+        assert_eq!(g7.to_string(), "G7(b9,#11)/B");
+        let berklee = Formatter::new(Style::Berklee)
+            .with_unicode(true)
+            .with_tension_order(TensionOrder::Descending);
+        assert_eq!(berklee.format(&g7), "G7(♯11, ♭9)/B");
+        assert_eq!(Formatter::new(Style::Jazz).format(&g7), "G7(b9,#11)/B");
+        let c7 = chord("C7(9,13)");
+        assert_eq!(Formatter::new(Style::RealBook).format(&c7), "C7(9,13)");
+    }
+
+    #[test]
+    fn round_trip() {
+        use Tension::*;
+        let roots = ["C", "F#", "Bb"];
+        let tensions: [&[Tension]; 8] = [
+            &[],
+            &[FlatFive],
+            &[SharpFive],
+            &[Thirteen],
+            &[Nine, Thirteen],
+            &[Nine, Eleven],
+            &[Eleven, Thirteen],
+            &[FlatNine, SharpEleven, Thirteen],
+        ];
+        for style in STYLES {
+            for unicode in [false, true] {
+                let formatter = Formatter::new(style).with_unicode(unicode);
+                for quality in Quality::enumerate() {
+                    for (root, tensions) in roots.iter().flat_map(|r| tensions.map(|t| (r, t))) {
+                        let chord = Chord::new(root.parse().unwrap(), *quality)
+                            .with_tensions(tensions.iter().copied().collect())
+                            .with_bass("E".parse().unwrap());
+                        let symbol = formatter.format(&chord);
+                        assert_eq!(symbol.parse(), Ok(chord), "{symbol}");
+                    }
+                }
+            }
+        }
+        let chord =
+            Chord::new("C".parse::<Tone>().unwrap(), Quality::Minor7).with_tension(FlatFive);
+        assert_eq!(chord.quality(), Quality::HalfDim7);
+        assert!(chord.tensions().is_empty());
+    }
+}
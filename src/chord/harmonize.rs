@@ -0,0 +1,244 @@
+use std::cmp::Reverse;
+
+use crate::{
+    core::Chord as CoreChord,
+    prelude::{
+        AccidentalSymbol, Chord, Degree, Interval, KeySignature, Keyboard, Number, Octave, Scale,
+        Scaled, Tone, ToneSymbol, Twelve,
+    },
+};
+
+/// Chords built on every degree of a scale by stacking steps of the scale,
+/// like triads by stacking thirds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Harmonization {
+    size: usize,
+    step: usize,
+}
+
+impl Harmonization {
+    /// Returns a `Harmonization` of chords with `size` tones, each of which is `step`
+    /// scale steps above the previous, like 2 for thirds and 3 for fourths.
+    pub fn new(size: usize, step: usize) -> Self {
+        Self { size, step }
+    }
+
+    pub fn triads() -> Self {
+        Self::new(3, 2)
+    }
+
+    pub fn sevenths() -> Self {
+        Self::new(4, 2)
+    }
+
+    pub fn ninths() -> Self {
+        Self::new(5, 2)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Returns a chord on each degree of the scale of `keyboard`, from the 1st.
+    /// - The root is a degree of the scale, and the others are degrees from the root,
+    ///   as `Chord<Degree>::to_pitch` expects.
+    pub fn chords<S: Scale, O: Octave>(
+        &self,
+        keyboard: &Keyboard<Scaled<S, O>>,
+    ) -> Vec<CoreChord<Degree>> {
+        (0..keyboard.octave().len())
+            .map(|i| {
+                let others = (1..self.size)
+                    .map(|k| Degree::new(1 + self.step * k).unwrap())
+                    .collect();
+                CoreChord::new(Degree::new(i + 1).unwrap(), others)
+            })
+            .collect()
+    }
+
+    /// Returns steps of the underlying `Octave` from the root of each chord tone,
+    /// like semitones on `Twelve`.
+    pub fn intervals<S: Scale, O: Octave>(
+        &self,
+        keyboard: &Keyboard<Scaled<S, O>>,
+        degree: Degree,
+    ) -> Vec<usize> {
+        let scaled = keyboard.octave();
+        let len = scaled.len();
+        let number = |n: usize| scaled.scale().convert(n % len) + n / len * scaled.octave().len();
+        let root = Number::value(&degree);
+        (1..self.size)
+            .map(|k| number(root + self.step * k) - number(root))
+            .collect()
+    }
+
+    /// Returns a named chord on each degree of the scale of `keyboard`, from the 1st.
+    /// - Roots are spelled under the key signature nearest to the scale, and each degree
+    ///   of a 7-tone scale takes its own letter, like "Bb" in F major and "C#" in D minor.
+    /// - Returns `None` for a chord that no quality and tensions describe.
+    pub fn named<S: Scale>(
+        &self,
+        keyboard: &Keyboard<Scaled<S, Twelve>>,
+    ) -> Vec<Option<Chord<Tone>>> {
+        let classes: Vec<usize> = (0..keyboard.octave().len())
+            .map(|i| usize::from(*keyboard.get_class(&i)) % 12)
+            .collect();
+        let roots = spell(&classes);
+        (0..keyboard.octave().len())
+            .map(|i| {
+                let degree = Degree::new(i + 1).unwrap();
+                let intervals: Vec<Interval> = self
+                    .intervals(keyboard, degree)
+                    .into_iter()
+                    .map(Interval::from)
+                    .collect();
+                Chord::from_intervals(roots[i], &intervals)
+            })
+            .collect()
+    }
+}
+
+/// Returns tones of pitch `classes` of a scale from its tonic, spelled under the key
+/// signature whose major scale shares the most classes.
+/// - Ties go to fewer accidentals, and then to flats like Gb major over F# major.
+fn spell(classes: &[usize]) -> Vec<Tone> {
+    use ToneSymbol::*;
+    let letters = [C, D, E, F, G, A, B];
+    let class = |tone: Tone| usize::from(tone) % 12;
+    let signature = (-7..=7)
+        .filter_map(KeySignature::new)
+        .max_by_key(|s| {
+            let shared = letters
+                .iter()
+                .filter(|l| classes.contains(&class(s.apply(**l))))
+                .count();
+            (shared, Reverse(s.value().abs()), Reverse(s.value()))
+        })
+        .unwrap();
+    let accidental = signature.accidental();
+    let tonic = classes.first().and_then(|c| {
+        letters
+            .iter()
+            .position(|l| class(signature.apply(*l)) == *c)
+    });
+    classes
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let Some(tonic) = tonic.filter(|_| classes.len() == 7) else {
+                return Tone::spell(*c, accidental);
+            };
+            spell_on(letters[(tonic + i) % 7], *c, accidental)
+        })
+        .collect()
+}
+
+/// Returns a tone of pitch `class` on `letter` as written, like "Cb" for 11 on C.
+/// - A tone that needs a double accidental is spelled with `accidental` instead.
+pub(crate) fn spell_on(letter: ToneSymbol, class: usize, accidental: AccidentalSymbol) -> Tone {
+    match (class + 12 - letter as usize) % 12 {
+        0 => Tone::new(letter, AccidentalSymbol::Natural),
+        1 => Tone::written(letter, AccidentalSymbol::Sharp),
+        11 => Tone::written(letter, AccidentalSymbol::Flat),
+        _ => Tone::spell(class, accidental),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn keyboard(key: &str, kind: ScaleKind) -> Keyboard<Scaled<Formula, Twelve>> {
+        let scale = Formula::named(&key.parse().unwrap(), kind);
+        Keyboard::new(Scaled::new(scale, Twelve))
+    }
+
+    fn named(
+        harmonization: Harmonization,
+        keyboard: &Keyboard<Scaled<Formula, Twelve>>,
+    ) -> Vec<Chord<Tone>> {
+        harmonization
+            .named(keyboard)
+            .into_iter()
+            .map(|c| c.unwrap())
+            .collect()
+    }
+
+    fn parse(symbols: &[&str]) -> Vec<Chord<Tone>> {
+        symbols.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn chords() {
+        let keyboard = keyboard("C", ScaleKind::Major);
+        let chords = Harmonization::triads().chords(&keyboard);
+        assert_eq!(chords.len(), 7);
+        let pitches: Vec<Tone> = chords[1]
+            .to_pitch(&keyboard)
+            .into_vec()
+            .into_iter()
+            .map(|p| **p.class())
+            .collect();
+        assert_eq!(pitches, ["D", "F", "A"].map(|t| t.parse().unwrap()));
+    }
+
+    #[test]
+    fn sevenths() {
+        let keyboard = keyboard("C", ScaleKind::Major);
+        let expected = ["Cmaj7", "Dm7", "Em7", "Fmaj7", "G7", "Am7", "Bm7b5"];
+        assert_eq!(
+            named(Harmonization::sevenths(), &keyboard),
+            parse(&expected)
+        );
+        let expected = ["Cmaj9", "Dm9", "Em7(b9)", "Fmaj9", "G9", "Am9", "Bm7b5(b9)"];
+        assert_eq!(named(Harmonization::ninths(), &keyboard), parse(&expected));
+    }
+
+    #[test]
+    fn harmonic_minor() {
+        let keyboard = keyboard("A", ScaleKind::HarmonicMinor);
+        let expected = ["Am", "Bdim", "C+", "Dm", "E", "F", "G#dim"];
+        assert_eq!(named(Harmonization::triads(), &keyboard), parse(&expected));
+    }
+
+    #[test]
+    fn spelling() {
+        let f_major = keyboard("F", ScaleKind::Major);
+        let chords = named(Harmonization::sevenths(), &f_major);
+        assert_eq!(chords[3].to_string(), "Bbmaj7");
+        assert_eq!(chords[6].to_string(), "Em7b5");
+        let d_minor = keyboard("D", ScaleKind::HarmonicMinor);
+        let expected = ["Dm", "Edim", "F+", "Gm", "A", "Bb", "C#dim"];
+        assert_eq!(named(Harmonization::triads(), &d_minor), parse(&expected));
+        let eb_minor = keyboard("Eb", ScaleKind::NaturalMinor);
+        let roots: Vec<Tone> = named(Harmonization::triads(), &eb_minor)
+            .iter()
+            .map(|c| *c.root())
+            .collect();
+        let expected = [
+            (E, Flat),
+            (F, Natural),
+            (G, Flat),
+            (A, Flat),
+            (B, Flat),
+            (C, Flat),
+            (D, Flat),
+        ];
+        assert_eq!(roots, expected.map(|(t, a)| Tone::written(t, a)));
+    }
+
+    #[test]
+    fn fourths() {
+        let keyboard = keyboard("C", ScaleKind::Major);
+        let quartal = Harmonization::new(3, 3);
+        assert_eq!(
+            quartal.intervals(&keyboard, Degree::new(2).unwrap()),
+            vec![5, 10]
+        );
+        assert_eq!(quartal.named(&keyboard)[3], None);
+    }
+}
@@ -1,3 +1,30 @@
+pub mod analysis;
+#[allow(clippy::module_inception)]
 pub mod chord;
+pub mod chord_formula;
+pub mod chord_scale;
+pub mod format;
+pub mod harmonize;
+pub mod neo_riemannian;
+pub mod progression;
+pub mod recognize;
+pub mod reharmonize;
+pub mod roman;
+pub mod tension;
+pub mod voice_leading;
+pub mod voicing;
 
+pub use analysis::*;
 pub use chord::*;
+pub use chord_formula::*;
+pub use chord_scale::*;
+pub use format::*;
+pub use harmonize::*;
+pub use neo_riemannian::*;
+pub use progression::*;
+pub use recognize::*;
+pub use reharmonize::*;
+pub use roman::*;
+pub use tension::*;
+pub use voice_leading::*;
+pub use voicing::*;
@@ -0,0 +1,272 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    fmt::Display,
+};
+
+use crate::{
+    chord::voicing::class,
+    prelude::{AccidentalSymbol, Chord, Quality, Tone},
+};
+
+/// Neo-Riemannian transformations between major and minor triads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transformation {
+    /// Keeps the root and the 5th and moves the 3rd, like "C" to "Cm".
+    Parallel,
+    /// Moves the root of a major triad down a half step, or the 5th of a minor triad up,
+    /// like "C" to "Em".
+    LeadingTone,
+    /// Moves the 5th of a major triad up a whole step, or the root of a minor triad down,
+    /// like "C" to "Am".
+    Relative,
+    /// R, L and P in turn, like "C" to "Fm".
+    Nebenverwandt,
+    /// L, P and R in turn, which keeps the 3rd, like "C" to "C#m".
+    Slide,
+    /// L, P and L in turn, which moves to the hexatonic pole, like "C" to "G#m".
+    Hexatonic,
+}
+
+impl Transformation {
+    pub fn enumerate() -> std::slice::Iter<'static, Transformation> {
+        use Transformation::*;
+        static TRANSFORMATIONS: [Transformation; 6] = [
+            Parallel,
+            LeadingTone,
+            Relative,
+            Nebenverwandt,
+            Slide,
+            Hexatonic,
+        ];
+        TRANSFORMATIONS.iter()
+    }
+
+    /// Returns P, L and R that this transformation consists of, in order.
+    pub fn steps(&self) -> &'static [Transformation] {
+        use Transformation::*;
+        match self {
+            Parallel => &[Parallel],
+            LeadingTone => &[LeadingTone],
+            Relative => &[Relative],
+            Nebenverwandt => &[Relative, LeadingTone, Parallel],
+            Slide => &[LeadingTone, Parallel, Relative],
+            Hexatonic => &[LeadingTone, Parallel, LeadingTone],
+        }
+    }
+}
+
+/// Writes the letter of the transformation, like "P" or "N".
+impl Display for Transformation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Transformation::*;
+        let letter = match self {
+            Parallel => "P",
+            LeadingTone => "L",
+            Relative => "R",
+            Nebenverwandt => "N",
+            Slide => "S",
+            Hexatonic => "H",
+        };
+        write!(f, "{letter}")
+    }
+}
+
+impl Chord<Tone> {
+    /// Returns the triad that `transformation` leads to.
+    /// - Tensions and the bass are dropped.
+    /// - A new root keeps the accidental of the old one, or is spelled with a flat for
+    ///   a major triad and with a sharp for a minor triad.
+    /// - Returns `None` if the chord is neither a major nor a minor triad.
+    pub fn transform(&self, transformation: Transformation) -> Option<Chord<Tone>> {
+        transformation
+            .steps()
+            .iter()
+            .try_fold(*self, |chord, step| chord.step(*step))
+    }
+
+    /// Returns the triad that `transformations` lead to in turn.
+    /// - Returns `None` if the chord is neither a major nor a minor triad.
+    pub fn transform_all(&self, transformations: &[Transformation]) -> Option<Chord<Tone>> {
+        transformations
+            .iter()
+            .try_fold(*self, |chord, t| chord.transform(*t))
+    }
+
+    fn step(&self, step: Transformation) -> Option<Chord<Tone>> {
+        let (semitones, quality) = match (self.quality(), step) {
+            (Quality::Major, Transformation::Parallel) => (0, Quality::Minor),
+            (Quality::Major, Transformation::LeadingTone) => (4, Quality::Minor),
+            (Quality::Major, Transformation::Relative) => (9, Quality::Minor),
+            (Quality::Minor, Transformation::Parallel) => (0, Quality::Major),
+            (Quality::Minor, Transformation::LeadingTone) => (8, Quality::Major),
+            (Quality::Minor, Transformation::Relative) => (3, Quality::Major),
+            _ => return None,
+        };
+        let accidental = match (self.root().accidental(), quality) {
+            (AccidentalSymbol::Natural, Quality::Major) => AccidentalSymbol::Flat,
+            (AccidentalSymbol::Natural, _) => AccidentalSymbol::Sharp,
+            (accidental, _) => *accidental,
+        };
+        let root = Tone::spell(class(self.root()) + semitones, accidental);
+        Some(Chord::new(root, quality))
+    }
+}
+
+/// Returns the fewest transformations in `allowed` that lead from `from` to `to`.
+/// - Transformations are tried in the order of `allowed` among paths of the same length.
+/// - Returns an empty path if both are the same triad, regardless of spelling.
+/// - Returns `None` if either is neither a major nor a minor triad, or `to` is not
+///   reachable.
+pub fn shortest_path(
+    from: &Chord<Tone>,
+    to: &Chord<Tone>,
+    allowed: &[Transformation],
+) -> Option<Vec<Transformation>> {
+    let key = |chord: &Chord<Tone>| (class(chord.root()), chord.quality());
+    let triad = |chord: &Chord<Tone>| matches!(chord.quality(), Quality::Major | Quality::Minor);
+    if !triad(from) || !triad(to) {
+        return None;
+    }
+    let mut previous = HashMap::from([(key(from), None)]);
+    let mut queue = VecDeque::from([*from]);
+    while let Some(chord) = queue.pop_front() {
+        if key(&chord) == key(to) {
+            let mut path = vec![];
+            let mut current = key(&chord);
+            while let Some(Some((before, transformation))) = previous.get(&current) {
+                path.push(*transformation);
+                current = *before;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for transformation in allowed {
+            let next = chord.transform(*transformation)?;
+            if let Entry::Vacant(entry) = previous.entry(key(&next)) {
+                entry.insert(Some((key(&chord), *transformation)));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// A point on the Tonnetz, a lattice of tones where one axis is perfect 5ths and
+/// the other is major 3rds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TonnetzPoint {
+    fifths: i32,
+    thirds: i32,
+}
+
+impl TonnetzPoint {
+    pub fn new(fifths: i32, thirds: i32) -> Self {
+        Self { fifths, thirds }
+    }
+
+    /// Returns the point of `tone`, which has 0 to 3 fifths and 0 to 2 thirds from C.
+    pub fn of(tone: &Tone) -> Self {
+        let class = class(tone) as i32;
+        (0..4)
+            .flat_map(|fifths| (0..3).map(move |thirds| Self::new(fifths, thirds)))
+            .find(|point| point.class() as i32 == class)
+            .unwrap()
+    }
+
+    /// Returns the points of the root, the 3rd and the 5th of a triad, which form
+    /// a triangle on the Tonnetz.
+    /// - Returns `None` if the chord is neither a major nor a minor triad.
+    pub fn triangle(chord: &Chord<Tone>) -> Option<[Self; 3]> {
+        let root = Self::of(chord.root());
+        let third = match chord.quality() {
+            Quality::Major => Self::new(root.fifths, root.thirds + 1),
+            Quality::Minor => Self::new(root.fifths + 1, root.thirds - 1),
+            _ => return None,
+        };
+        Some([root, third, Self::new(root.fifths + 1, root.thirds)])
+    }
+
+    pub fn fifths(&self) -> i32 {
+        self.fifths
+    }
+
+    pub fn thirds(&self) -> i32 {
+        self.thirds
+    }
+
+    /// Returns semitones above C of the tone on this point.
+    pub fn class(&self) -> usize {
+        (7 * self.fifths + 4 * self.thirds).rem_euclid(12) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn chord(symbol: &str) -> Chord<Tone> {
+        symbol.parse().unwrap()
+    }
+
+    #[test]
+    fn transform() {
+        use Transformation::*;
+        let c = chord("C");
+        let expected = ["Cm", "Em", "Am", "Fm", "C#m", "G#m"];
+        for (t, expected) in Transformation::enumerate().zip(expected) {
+            assert_eq!(c.transform(*t), Some(chord(expected)), "{t}");
+        }
+        assert_eq!(chord("Cm").transform(LeadingTone), Some(chord("Ab")));
+        assert_eq!(chord("Fm").transform(Relative), Some(chord("Ab")));
+        assert_eq!(chord("E").transform(Relative), Some(chord("C#m")));
+        assert_eq!(c.transform_all(&[Parallel, Parallel]), Some(c));
+        assert_eq!(chord("C7").transform(Parallel), None);
+        for t in Transformation::enumerate() {
+            let there = c.transform(*t).unwrap();
+            let back: Vec<Transformation> = t.steps().iter().rev().copied().collect();
+            assert_eq!(there.transform_all(&back), Some(c));
+        }
+    }
+
+    #[test]
+    fn path() {
+        use Transformation::*;
+        let prl = [Parallel, LeadingTone, Relative];
+        assert_eq!(
+            shortest_path(&chord("C"), &chord("Am"), &prl),
+            Some(vec![Relative])
+        );
+        assert_eq!(
+            shortest_path(&chord("C"), &chord("Fm"), &prl).map(|p| p.len()),
+            Some(3)
+        );
+        let path = shortest_path(&chord("C"), &chord("F#"), &prl).unwrap();
+        assert_eq!(path.len(), 4);
+        assert_eq!(chord("C").transform_all(&path), Some(chord("Gb")));
+        let all: Vec<Transformation> = Transformation::enumerate().copied().collect();
+        assert_eq!(
+            shortest_path(&chord("C"), &chord("Abm"), &all),
+            Some(vec![Hexatonic])
+        );
+        assert_eq!(shortest_path(&chord("C"), &chord("Db"), &[]), None);
+        assert_eq!(shortest_path(&chord("C"), &chord("C"), &[]), Some(vec![]));
+        assert_eq!(shortest_path(&chord("C"), &chord("Cdim"), &prl), None);
+    }
+
+    #[test]
+    fn tonnetz() {
+        let point = |s: &str| TonnetzPoint::of(&s.parse().unwrap());
+        assert_eq!(point("C"), TonnetzPoint::new(0, 0));
+        assert_eq!(point("G"), TonnetzPoint::new(1, 0));
+        assert_eq!(point("E"), TonnetzPoint::new(0, 1));
+        assert_eq!(point("Db"), point("C#"));
+        let classes = |s: &str| {
+            TonnetzPoint::triangle(&chord(s))
+                .unwrap()
+                .map(|p| p.class())
+        };
+        assert_eq!(classes("C"), [0, 4, 7]);
+        assert_eq!(classes("Am"), [9, 0, 4]);
+        assert_eq!(TonnetzPoint::triangle(&chord("Csus4")), None);
+    }
+}
@@ -0,0 +1,530 @@
+use std::collections::HashMap;
+
+use crate::prelude::{
+    AlteredDegree, Chord, Degree, Diatonic, DiatonicQuality, Formula, Quality, RomanNumeral, Tone,
+};
+
+/// Harmonic functions of chords in a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HarmonicFunction {
+    Tonic,
+    Subdominant,
+    Dominant,
+}
+
+impl HarmonicFunction {
+    /// Returns the function of a diatonic chord on `degree`, like `Subdominant` for ii.
+    /// - The 3rd and the 6th are taken as tonic substitutes.
+    pub fn of(degree: Degree) -> Self {
+        match degree.value() % 7 {
+            2 | 4 => HarmonicFunction::Subdominant,
+            5 | 0 => HarmonicFunction::Dominant,
+            _ => HarmonicFunction::Tonic,
+        }
+    }
+
+    /// Returns `true` if a chord of this function can move to a chord of `next`,
+    /// which is any move but from a dominant to a subdominant.
+    pub fn can_move_to(&self, next: HarmonicFunction) -> bool {
+        !(*self == HarmonicFunction::Dominant && next == HarmonicFunction::Subdominant)
+    }
+}
+
+/// Cadences that end a progression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cadence {
+    /// V–I.
+    Authentic,
+    /// IV–I.
+    Plagal,
+    /// A progression ending on V.
+    Half,
+    /// V–vi.
+    Deceptive,
+    /// iv6–V in a minor key, whose bass falls by a half step.
+    /// - A generator in a major key or a mode ends on a half cadence instead.
+    Phrygian,
+}
+
+impl Cadence {
+    /// Returns degrees of the closing chords.
+    fn degrees(&self) -> &'static [usize] {
+        match self {
+            Cadence::Authentic => &[5, 1],
+            Cadence::Plagal => &[4, 1],
+            Cadence::Half => &[5],
+            Cadence::Deceptive => &[5, 6],
+            Cadence::Phrygian => &[4, 5],
+        }
+    }
+}
+
+/// Durations of chords in beats, which never cross a bar line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HarmonicRhythm {
+    beats: usize,
+    durations: Vec<usize>,
+}
+
+impl HarmonicRhythm {
+    /// Returns a `HarmonicRhythm` of bars with `beats` beats, where each chord lasts one
+    /// of `durations` beats.
+    /// - Durations longer than a bar or of 0 beats are ignored.
+    pub fn new(beats: usize, durations: &[usize]) -> Self {
+        let beats = beats.max(1);
+        let mut durations: Vec<usize> = durations
+            .iter()
+            .filter(|d| (1..=beats).contains(*d))
+            .copied()
+            .collect();
+        if durations.is_empty() {
+            durations.push(beats);
+        }
+        Self { beats, durations }
+    }
+
+    /// Returns a `HarmonicRhythm` of a chord per bar of `beats` beats.
+    pub fn per_bar(beats: usize) -> Self {
+        Self::new(beats, &[beats])
+    }
+
+    pub fn beats(&self) -> usize {
+        self.beats
+    }
+
+    pub fn durations(&self) -> &[usize] {
+        &self.durations
+    }
+}
+
+impl Default for HarmonicRhythm {
+    fn default() -> Self {
+        Self::per_bar(4)
+    }
+}
+
+/// A chord progression in a key, with a duration of each chord.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progression {
+    key: Formula,
+    numerals: Vec<RomanNumeral>,
+    durations: Vec<usize>,
+}
+
+impl Progression {
+    pub fn key(&self) -> &Formula {
+        &self.key
+    }
+
+    pub fn numerals(&self) -> &[RomanNumeral] {
+        &self.numerals
+    }
+
+    /// Returns durations of the chords in beats.
+    pub fn durations(&self) -> &[usize] {
+        &self.durations
+    }
+
+    /// Returns the chords in the key.
+    pub fn chords(&self) -> Vec<Chord<Tone>> {
+        self.numerals
+            .iter()
+            .filter_map(|n| n.to_chord(&self.key))
+            .collect()
+    }
+}
+
+/// Transition counts between Roman numerals, learned from example progressions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MarkovModel {
+    transitions: HashMap<RomanNumeral, Vec<(RomanNumeral, usize)>>,
+}
+
+impl MarkovModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts every move from a chord to the next in `progression`.
+    pub fn train(&mut self, progression: &[RomanNumeral]) {
+        for pair in progression.windows(2) {
+            let nexts = self.transitions.entry(pair[0].clone()).or_default();
+            match nexts.iter_mut().find(|(n, _)| *n == pair[1]) {
+                Some((_, count)) => *count += 1,
+                None => nexts.push((pair[1].clone(), 1)),
+            }
+        }
+    }
+
+    /// Returns chords that followed `numeral`, with the number of times.
+    pub fn next(&self, numeral: &RomanNumeral) -> &[(RomanNumeral, usize)] {
+        self.transitions
+            .get(numeral)
+            .map(|n| n.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// Generates chord progressions in a key from a seed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressionGenerator {
+    key: Formula,
+    minor: bool,
+    model: Option<MarkovModel>,
+    length: usize,
+    cadence: Option<Cadence>,
+    rhythm: HarmonicRhythm,
+    sevenths: bool,
+    seed: u64,
+}
+
+impl ProgressionGenerator {
+    /// Returns a generator of tonic, subdominant and dominant moves in `key`.
+    /// - The dominant and the leading-tone chords of a minor key use the raised 7th.
+    pub fn new(key: &Diatonic) -> Self {
+        Self {
+            minor: key.quality() == DiatonicQuality::Minor,
+            ..Self::in_mode(key.into())
+        }
+    }
+
+    /// Returns a generator in a mode, like the dorian, whose chords are all diatonic.
+    pub fn in_mode(mode: Formula) -> Self {
+        Self {
+            key: mode,
+            minor: false,
+            model: None,
+            length: 8,
+            cadence: Some(Cadence::Authentic),
+            rhythm: HarmonicRhythm::default(),
+            sevenths: false,
+            seed: 0,
+        }
+    }
+
+    /// Returns a generator that follows `model` instead of the functional rules.
+    /// - A chord that `model` has never seen moves along the functional rules.
+    pub fn with_model(self, model: MarkovModel) -> Self {
+        Self {
+            model: Some(model),
+            ..self
+        }
+    }
+
+    /// Sets the number of chords, which is 8 by default.
+    pub fn with_length(self, length: usize) -> Self {
+        Self { length, ..self }
+    }
+
+    /// Sets the closing cadence, which is `Authentic` by default, or `None` for a free end.
+    pub fn with_cadence(self, cadence: Option<Cadence>) -> Self {
+        Self { cadence, ..self }
+    }
+
+    pub fn with_rhythm(self, rhythm: HarmonicRhythm) -> Self {
+        Self { rhythm, ..self }
+    }
+
+    /// Uses 7th chords instead of triads.
+    pub fn with_sevenths(self, sevenths: bool) -> Self {
+        Self { sevenths, ..self }
+    }
+
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+
+    /// Returns a progression that starts on the tonic and ends on the cadence.
+    /// - The same seed always returns the same progression.
+    /// - The cadence takes the whole progression if the length is not longer than it,
+    ///   and is shortened from its start if the length is shorter.
+    /// - A Phrygian cadence is only in a minor key, and becomes a half cadence otherwise.
+    pub fn generate(&self) -> Progression {
+        let mut random = Random::new(self.seed);
+        let kind = match self.cadence {
+            Some(Cadence::Phrygian) if !self.minor => Some(Cadence::Half),
+            kind => kind,
+        };
+        let cadence = kind.map(|c| c.degrees()).unwrap_or_default();
+        let cadence = &cadence[cadence.len().saturating_sub(self.length)..];
+        let free = self.length.saturating_sub(cadence.len());
+
+        let mut numerals: Vec<RomanNumeral> = vec![];
+        for i in 0..free {
+            let next = cadence
+                .first()
+                .filter(|_| i + 1 == free)
+                .map(|d| self.numeral(*d));
+            let numeral = match numerals.last() {
+                None => self.numeral(1),
+                Some(previous) => self.choose(previous, next.as_ref(), &mut random),
+            };
+            numerals.push(numeral);
+        }
+        for (i, degree) in cadence.iter().enumerate() {
+            let numeral = self.numeral(*degree);
+            numerals.push(match (kind, i) {
+                (Some(Cadence::Phrygian), 0) if cadence.len() == 2 => {
+                    RomanNumeral::new(numeral.degree(), numeral.quality(), 1)
+                }
+                _ => numeral,
+            });
+        }
+        let durations = self.durations(numerals.len(), &mut random);
+        Progression {
+            key: self.key.clone(),
+            numerals,
+            durations,
+        }
+    }
+
+    /// Returns a chord after `previous` that can move to `next`, if given.
+    /// - The chord repeats neither `previous` nor `next`.
+    fn choose(
+        &self,
+        previous: &RomanNumeral,
+        next: Option<&RomanNumeral>,
+        random: &mut Random,
+    ) -> RomanNumeral {
+        let function = |n: &RomanNumeral| HarmonicFunction::of(n.degree().degree());
+        let fits = |n: &RomanNumeral| {
+            n != previous
+                && next.is_none_or(|next| n != next && function(n).can_move_to(function(next)))
+        };
+        let learned: Vec<(RomanNumeral, usize)> = self
+            .model
+            .iter()
+            .flat_map(|m| m.next(previous))
+            .filter(|(n, _)| fits(n))
+            .cloned()
+            .collect();
+        let candidates = match learned.is_empty() {
+            false => learned,
+            true => (1..=7)
+                .map(|d| self.numeral(d))
+                .filter(|n| fits(n) && function(previous).can_move_to(function(n)))
+                .map(|n| {
+                    let weight = Self::weight(function(previous), function(&n))
+                        * Self::weight_in_function(n.degree().degree().value());
+                    (n, weight)
+                })
+                .collect(),
+        };
+        let weights: Vec<usize> = candidates.iter().map(|(_, w)| *w).collect();
+        candidates[random.weighted(&weights)].0.clone()
+    }
+
+    /// Returns a weight of a move between functions, which favors T–S–D–T.
+    fn weight(from: HarmonicFunction, to: HarmonicFunction) -> usize {
+        use HarmonicFunction::*;
+        match (from, to) {
+            (Tonic, Subdominant) | (Subdominant, Dominant) | (Dominant, Tonic) => 3,
+            (Tonic, Dominant) => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns a weight of a degree among the chords of the same function.
+    fn weight_in_function(degree: usize) -> usize {
+        match degree {
+            1 | 4 | 5 => 4,
+            2 | 6 => 3,
+            _ => 1,
+        }
+    }
+
+    /// Returns a diatonic chord on `degree`, which is on the raised 7th in a minor key.
+    fn numeral(&self, degree: usize) -> RomanNumeral {
+        let mut distances = self.key.distances().to_vec();
+        if self.minor && (degree == 5 || degree == 7) {
+            distances[6] = 11;
+        }
+        let len = distances.len();
+        let size = if self.sevenths { 4 } else { 3 };
+        let distance = |i: usize| distances[i % len] + 12 * (i / len);
+        let root = degree - 1;
+        let intervals: Vec<_> = (1..size)
+            .map(|k| (distance(root + 2 * k) - distance(root)).into())
+            .collect();
+        let quality = Quality::from_intervals(&intervals).unwrap_or(Quality::Major);
+        let alteration = (self.minor && degree == 7) as i32;
+        let degree = AlteredDegree::new(Degree::new(degree).unwrap(), alteration);
+        RomanNumeral::new(degree, quality, 0)
+    }
+
+    /// Returns a duration of each of `count` chords along the harmonic rhythm.
+    /// - The last chord lasts to the end of its bar.
+    fn durations(&self, count: usize, random: &mut Random) -> Vec<usize> {
+        let beats = self.rhythm.beats();
+        let mut durations: Vec<usize> = vec![];
+        let mut position = 0;
+        for _ in 0..count {
+            let left = beats - position % beats;
+            let fitting: Vec<usize> = self
+                .rhythm
+                .durations()
+                .iter()
+                .filter(|d| **d <= left)
+                .copied()
+                .collect();
+            let duration = match fitting.is_empty() {
+                true => left,
+                false => fitting[random.below(fitting.len())],
+            };
+            durations.push(duration);
+            position += duration;
+        }
+        if let Some(last) = durations.last_mut() {
+            *last += (beats - position % beats) % beats;
+        }
+        durations
+    }
+}
+
+/// A pseudo random number generator of SplitMix64, which needs no dependency.
+#[derive(Debug, Clone)]
+pub(crate) struct Random(u64);
+
+impl Random {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number below `n`.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// Returns an index picked in proportion to `weights`.
+    pub(crate) fn weighted(&mut self, weights: &[usize]) -> usize {
+        let total: usize = weights.iter().sum();
+        let mut pick = self.below(total.max(1));
+        weights
+            .iter()
+            .position(|w| {
+                let hit = pick < *w;
+                pick = pick.saturating_sub(*w);
+                hit
+            })
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn numerals(symbols: &[&str]) -> Vec<RomanNumeral> {
+        symbols.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn functional() {
+        let key = Diatonic::major(&"C".parse().unwrap());
+        for seed in 0..20 {
+            let progression = ProgressionGenerator::new(&key).with_seed(seed).generate();
+            let generated = progression.numerals();
+            assert_eq!(generated.len(), 8);
+            assert_eq!(generated[0], "I".parse().unwrap());
+            assert_eq!(generated[6..], numerals(&["V", "I"]));
+            for pair in generated.windows(2) {
+                let function = |n: &RomanNumeral| HarmonicFunction::of(n.degree().degree());
+                assert!(function(&pair[0]).can_move_to(function(&pair[1])));
+            }
+        }
+        let generator = ProgressionGenerator::new(&key).with_seed(7);
+        assert_eq!(generator.generate(), generator.generate());
+    }
+
+    #[test]
+    fn no_repeats() {
+        let key = Diatonic::major(&"C".parse().unwrap());
+        let cadences = [
+            Cadence::Authentic,
+            Cadence::Plagal,
+            Cadence::Half,
+            Cadence::Deceptive,
+        ];
+        for (seed, cadence) in (0..40).zip(cadences.into_iter().cycle()) {
+            let progression = ProgressionGenerator::new(&key)
+                .with_length(3 + seed as usize % 6)
+                .with_cadence(Some(cadence))
+                .with_seed(seed)
+                .generate();
+            let generated = progression.numerals();
+            assert!(
+                generated.windows(2).all(|pair| pair[0] != pair[1]),
+                "{seed}: {generated:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn minor_and_cadence() {
+        let key = Diatonic::minor(&"A".parse().unwrap());
+        let progression = ProgressionGenerator::new(&key)
+            .with_length(4)
+            .with_cadence(Some(Cadence::Deceptive))
+            .with_sevenths(true)
+            .generate();
+        let chords = progression.chords();
+        assert_eq!(chords[0], "Am7".parse().unwrap());
+        assert_eq!(chords[2..], ["E7", "Fmaj7"].map(|c| c.parse().unwrap()));
+        let phrygian = |key: &Diatonic| {
+            let progression = ProgressionGenerator::new(key)
+                .with_length(4)
+                .with_cadence(Some(Cadence::Phrygian))
+                .generate();
+            progression.numerals()[2..].to_vec()
+        };
+        assert_eq!(phrygian(&key), numerals(&["iv6", "V"]));
+        let major = Diatonic::major(&"C".parse().unwrap());
+        let ending = phrygian(&major);
+        assert_eq!(ending[1], "V".parse().unwrap());
+        assert_ne!(ending[0], "IV6".parse().unwrap());
+    }
+
+    #[test]
+    fn markov() {
+        let mut model = MarkovModel::new();
+        model.train(&numerals(&["I", "vi", "ii", "V", "I"]));
+        model.train(&numerals(&["I", "vi", "IV", "V", "I"]));
+        assert_eq!(model.next(&"vi".parse().unwrap()).len(), 2);
+        let key = Diatonic::major(&"G".parse().unwrap());
+        let progression = ProgressionGenerator::new(&key)
+            .with_model(model)
+            .with_length(5)
+            .with_seed(3)
+            .generate();
+        let numerals = progression.numerals();
+        assert_eq!(numerals[1], "vi".parse().unwrap());
+        assert!(["ii", "IV"].contains(&numerals[2].to_string().as_str()));
+        assert_eq!(progression.chords()[1], "Em".parse().unwrap());
+    }
+
+    #[test]
+    fn rhythm() {
+        let key = Diatonic::major(&"F".parse().unwrap());
+        let progression = ProgressionGenerator::new(&key)
+            .with_rhythm(HarmonicRhythm::new(4, &[1, 2, 3]))
+            .with_length(6)
+            .with_seed(11)
+            .generate();
+        let durations = progression.durations();
+        assert_eq!(durations.len(), 6);
+        assert_eq!(durations.iter().sum::<usize>() % 4, 0);
+        let mut position = 0;
+        for duration in durations {
+            assert!(position % 4 + duration <= 4);
+            position += duration;
+        }
+    }
+}
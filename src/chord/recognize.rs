@@ -0,0 +1,292 @@
+use crate::{
+    core::Chord as CoreChord,
+    prelude::{AccidentalSymbol, Chord, Interval, Pitch, Quality, Tension, Tensions, Tone},
+};
+
+/// An interpretation of a set of tones as a `Chord`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recognition {
+    chord: Chord<Tone>,
+    omitted: Vec<Interval>,
+    rootless: bool,
+    penalty: usize,
+}
+
+impl Recognition {
+    pub fn chord(&self) -> &Chord<Tone> {
+        &self.chord
+    }
+
+    pub fn into_chord(self) -> Chord<Tone> {
+        self.chord
+    }
+
+    /// Returns intervals from the root of the chord tones that are not sounded,
+    /// like the 5th of a shell voicing.
+    pub fn omitted(&self) -> &[Interval] {
+        &self.omitted
+    }
+
+    /// Returns `true` if the root is not sounded.
+    pub fn is_rootless(&self) -> bool {
+        self.rootless
+    }
+
+    /// Returns `true` if the bass is another chord tone than the root, like "C/E".
+    pub fn is_inversion(&self) -> bool {
+        self.chord.inversion().is_some_and(|i| i > 0)
+    }
+
+    /// Returns `true` if the bass is not a chord tone, like "C/D".
+    pub fn is_slash(&self) -> bool {
+        self.chord.inversion().is_none()
+    }
+}
+
+/// Returns interpretations of `tones` as chords, from the most plausible.
+/// - The first tone is taken as the bass.
+/// - Returns an empty vector if less than 2 different tones are given.
+pub fn recognize(tones: &[Tone]) -> Vec<Recognition> {
+    match tones.first() {
+        Some(bass) => recognize_classes(tones, bass),
+        None => vec![],
+    }
+}
+
+/// Returns interpretations of `chord` as chords, from the most plausible.
+/// - The lowest pitch is taken as the bass.
+pub fn recognize_pitches(chord: &CoreChord<Pitch<Tone>>) -> Vec<Recognition> {
+    let pitches: Vec<_> = std::iter::once(chord.root())
+        .chain(chord.others())
+        .collect();
+    let number = |p: &Pitch<Tone>| p.oct() * 12 + usize::from(*p.class());
+    let bass = pitches.iter().min_by_key(|p| number(p)).unwrap();
+    let tones: Vec<Tone> = pitches.iter().map(|p| *p.class()).collect();
+    recognize_classes(&tones, bass.class())
+}
+
+/// Returns interpretations of MIDI note numbers as chords, from the most plausible.
+/// - The lowest note is taken as the bass, and black keys are spelled with sharps.
+pub fn recognize_midi(notes: &[u8]) -> Vec<Recognition> {
+    let spell = |n: &u8| Tone::spell(*n as usize, AccidentalSymbol::Sharp);
+    let tones: Vec<Tone> = notes.iter().map(spell).collect();
+    match notes.iter().min() {
+        Some(bass) => recognize_classes(&tones, &spell(bass)),
+        None => vec![],
+    }
+}
+
+fn recognize_classes(tones: &[Tone], bass: &Tone) -> Vec<Recognition> {
+    let mut classes: Vec<usize> = tones.iter().map(|t| usize::from(*t) % 12).collect();
+    classes.sort();
+    classes.dedup();
+    if classes.len() < 2 {
+        return vec![];
+    }
+    let flats = tones
+        .iter()
+        .filter(|t| t.accidental() == &AccidentalSymbol::Flat)
+        .count();
+    let sharps = tones
+        .iter()
+        .filter(|t| t.accidental() == &AccidentalSymbol::Sharp)
+        .count();
+    let accidental = if flats > sharps {
+        AccidentalSymbol::Flat
+    } else {
+        AccidentalSymbol::Sharp
+    };
+    let spell = |class: usize| {
+        tones
+            .iter()
+            .find(|t| usize::from(**t) % 12 == class)
+            .copied()
+            .unwrap_or_else(|| Tone::spell(class, accidental))
+    };
+
+    let bass_class = usize::from(*bass) % 12;
+    let mut recognitions: Vec<(Recognition, usize)> = vec![];
+    for root in 0..12 {
+        // A bass that is not a chord tone is also tried, like "C/D".
+        let mut sounded = vec![(classes.clone(), 0)];
+        if bass_class != root && classes.len() > 2 {
+            let upper = classes.iter().filter(|&&c| c != bass_class).copied();
+            sounded.push((upper.collect(), 1));
+        }
+        for (sounded, slash) in sounded {
+            let relative: Vec<usize> = sounded.iter().map(|c| (c + 12 - root) % 12).collect();
+            for (rank, quality) in Quality::enumerate().enumerate() {
+                let Some((omitted, tensions, penalty, rootless)) = interpret(&relative, *quality)
+                else {
+                    continue;
+                };
+                let mut chord = Chord::new(spell(root), *quality).with_tensions(tensions);
+                let mut penalty = penalty + slash;
+                if bass_class != root && !rootless {
+                    chord = chord.with_bass(*bass);
+                    penalty += 2;
+                }
+                let recognition = Recognition {
+                    chord,
+                    omitted,
+                    rootless,
+                    penalty,
+                };
+                recognitions.push((recognition, rank));
+            }
+        }
+    }
+    recognitions.sort_by_key(|(r, rank)| (r.penalty, *rank));
+
+    // A chord like "C9" is also found as "C7(9)", so only the simplest spelling is kept.
+    let mut unique: Vec<Recognition> = vec![];
+    for (recognition, _) in recognitions {
+        let sound = |r: &Recognition| {
+            let root = usize::from(*r.chord.root()) % 12;
+            let mut classes: Vec<usize> = r
+                .chord
+                .intervals()
+                .iter()
+                .map(|i| (root + i.value()) % 12)
+                .collect();
+            classes.sort();
+            (root, classes, r.chord.bass().copied())
+        };
+        if !unique.iter().any(|u| sound(u) == sound(&recognition)) {
+            unique.push(recognition);
+        }
+    }
+    unique
+}
+
+/// Interprets semitone classes from a root as `quality` with tensions.
+/// - Returns omitted intervals, tensions, a penalty and whether the root is omitted.
+/// - Returns `None` if any class is not explained, or an essential tone is omitted.
+fn interpret(
+    relative: &[usize],
+    quality: Quality,
+) -> Option<(Vec<Interval>, Tensions, usize, bool)> {
+    let intervals = quality.intervals();
+    let has = |i: &Interval| relative.contains(&(i.value() % 12));
+    let fifth = intervals.iter().any(|i| i.value() == 7);
+    let mut tensions = Tensions::new();
+    for class in relative {
+        if *class == 0 || intervals.iter().any(|i| i.value() % 12 == *class) {
+            continue;
+        }
+        let tension = Tension::enumerate()
+            .filter(|t| t.interval().value() % 12 == *class)
+            .find(|t| match t.replaces() {
+                // "b5" and "#5" replace the fifth, while "#11" and "b13" sound over it.
+                Some(replaced) if replaced.value() == 7 => !relative.contains(&7) && fifth,
+                _ => true,
+            })?;
+        tensions.insert(*tension);
+    }
+
+    let rootless = !relative.contains(&0);
+    // An altered fifth changes the chord more than an extension does.
+    let altered = tensions
+        .iter()
+        .filter(|t| t.replaces().is_some_and(|r| r.value() == 7))
+        .count();
+    let mut penalty = 2 * tensions.len() + altered;
+    if rootless {
+        if intervals.len() < 3 || relative.len() < 3 {
+            return None;
+        }
+        penalty += 3;
+    }
+    let mut omitted = vec![];
+    for interval in intervals {
+        if has(interval) {
+            continue;
+        }
+        let replaced = tensions
+            .iter()
+            .any(|t| t.replaces().is_some_and(|r| r == *interval));
+        match interval.value() {
+            _ if replaced => continue,
+            7 | 14 | 17 if intervals.len() > 2 => penalty += 1,
+            _ => return None,
+        }
+        omitted.push(*interval);
+    }
+    if rootless {
+        omitted.insert(0, 0.into());
+    }
+    Some((omitted, tensions, penalty, rootless))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn tones(tones: &[&str]) -> Vec<Tone> {
+        tones.iter().map(|t| t.parse().unwrap()).collect()
+    }
+
+    fn best(tones_: &[&str]) -> Chord<Tone> {
+        recognize(&tones(tones_)).remove(0).into_chord()
+    }
+
+    #[test]
+    fn root_position() {
+        assert_eq!(best(&["C", "E", "G", "B"]), "Cmaj7".parse().unwrap());
+        assert_eq!(best(&["D", "F", "A", "C"]), "Dm7".parse().unwrap());
+        assert_eq!(best(&["C", "E", "G", "A"]), "C6".parse().unwrap());
+        assert_eq!(
+            best(&["G", "B", "D", "F", "Ab", "C#"]),
+            "G7(b9,#11)".parse().unwrap()
+        );
+        assert_eq!(best(&["Bb", "D", "F", "Ab"]), "Bb7".parse().unwrap());
+    }
+
+    #[test]
+    fn inversion() {
+        let recognition = recognize(&tones(&["E", "G", "C"])).remove(0);
+        assert_eq!(recognition.chord(), &"C/E".parse().unwrap());
+        assert!(recognition.is_inversion());
+        let recognition = recognize(&tones(&["Db", "C", "E", "G"])).remove(0);
+        assert_eq!(recognition.chord(), &"C/Db".parse().unwrap());
+        assert!(recognition.is_slash());
+    }
+
+    #[test]
+    fn omitted() {
+        let recognition = recognize(&tones(&["C", "E", "Bb"])).remove(0);
+        assert_eq!(recognition.chord(), &"C7".parse().unwrap());
+        assert_eq!(recognition.omitted(), &[7.into()]);
+    }
+
+    #[test]
+    fn rootless() {
+        let recognitions = recognize(&tones(&["E", "Bb", "D", "G"]));
+        let rootless = recognitions
+            .iter()
+            .find(|r| r.chord() == &"C9".parse().unwrap())
+            .unwrap();
+        assert!(rootless.is_rootless());
+        assert_eq!(rootless.omitted(), &[0.into()]);
+    }
+
+    #[test]
+    fn pitches_and_midi() {
+        let root = Pitch::new("C".parse().unwrap(), 4);
+        let chord = Chord::new(*root.class(), Quality::Dominant13).into_class();
+        assert_eq!(
+            recognize(&chord.into_vec())[0].chord(),
+            &"C13".parse().unwrap()
+        );
+        let pitches = Chord::new(root, Quality::Minor9).into_pitch();
+        assert_eq!(
+            recognize_pitches(&pitches)[0].chord(),
+            &"Cm9".parse().unwrap()
+        );
+        assert_eq!(
+            recognize_midi(&[64, 67, 72])[0].chord(),
+            &"C/E".parse().unwrap()
+        );
+        assert!(recognize_midi(&[60, 72]).is_empty());
+    }
+}
@@ -0,0 +1,337 @@
+use crate::prelude::{
+    AccidentalSymbol, Chord, Degree, Diatonic, Formula, Harmonization, Interval, KeySignature,
+    Keyboard, Quality, ScaleKind, Scaled, Tone, Twelve,
+};
+
+/// Kinds of substitutions that `Reharmonizer` suggests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Substitution {
+    /// The dominant 7th of the target, like "A7" before "Dm" in C major.
+    SecondaryDominant,
+    /// The leading-tone 7th of the target, like "C#dim7" before "Dm" in C major.
+    SecondaryLeadingTone,
+    /// The dominant 7th a tritone away, like "Db7" for "G7".
+    TritoneSubstitute,
+    /// The dominant 7th on the lowered 7th of the target, like "Bb7" before "C".
+    Backdoor,
+    /// The ii7 of a dominant put before it, like "Dm7 G7" for "G7".
+    RelatedTwo,
+    /// A chord on the same degree of a parallel mode, like "Fm" for "F" in C major.
+    Borrowed,
+}
+
+/// A suggestion of chords that take the place of, or lead to, a chord.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    substitution: Substitution,
+    chords: Vec<Chord<Tone>>,
+}
+
+impl Suggestion {
+    pub fn substitution(&self) -> Substitution {
+        self.substitution
+    }
+
+    /// Returns the suggested chords in order, like "Dm7" and "G7" of a related ii–V.
+    pub fn chords(&self) -> &[Chord<Tone>] {
+        &self.chords
+    }
+}
+
+/// Modes that chords are borrowed from, with the same tonic as the key.
+const MODES: [ScaleKind; 9] = {
+    use ScaleKind::*;
+    [
+        Major,
+        NaturalMinor,
+        HarmonicMinor,
+        MelodicMinor,
+        Dorian,
+        Phrygian,
+        Lydian,
+        Mixolydian,
+        Locrian,
+    ]
+};
+
+/// Suggests substitutions for chords in a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reharmonizer {
+    key: Formula,
+}
+
+impl Reharmonizer {
+    pub fn new(key: &Diatonic) -> Self {
+        Self { key: key.into() }
+    }
+
+    pub fn key(&self) -> &Formula {
+        &self.key
+    }
+
+    /// Returns the dominant 7th that resolves to `target`.
+    /// - Returns `None` if `target` is not a major or minor triad of the key other than
+    ///   the tonic, or the 7th chord of the key on such a triad.
+    pub fn secondary_dominant(&self, target: &Chord<Tone>) -> Option<Chord<Tone>> {
+        self.tonicizable(target)?;
+        let root = spell(target.root(), 7);
+        Some(Chord::new(root, Quality::Dominant7))
+    }
+
+    /// Returns the leading-tone 7th that resolves to `target`, which is half-diminished
+    /// before a major triad and diminished before a minor triad.
+    /// - Returns `None` if `target` is not a major or minor triad of the key other than
+    ///   the tonic, or the 7th chord of the key on such a triad.
+    pub fn secondary_leading_tone(&self, target: &Chord<Tone>) -> Option<Chord<Tone>> {
+        let quality = match self.tonicizable(target)? {
+            Quality::Major => Quality::HalfDim7,
+            _ => Quality::Dim7,
+        };
+        let root = Tone::spell(
+            (usize::from(*target.root()) + 11) % 12,
+            match target.root().accidental() {
+                AccidentalSymbol::Flat => AccidentalSymbol::Flat,
+                _ => AccidentalSymbol::Sharp,
+            },
+        );
+        Some(Chord::new(root, quality))
+    }
+
+    /// Returns the dominant 7th a tritone away from `dominant`, which shares its 3rd
+    /// and 7th.
+    /// - Returns `None` if `dominant` has no major 3rd and minor 7th.
+    pub fn tritone_substitute(&self, dominant: &Chord<Tone>) -> Option<Chord<Tone>> {
+        is_dominant(dominant).then(|| {
+            let root = (usize::from(*dominant.root()) + 6) % 12;
+            Chord::new(
+                Tone::spell(root, AccidentalSymbol::Flat),
+                Quality::Dominant7,
+            )
+        })
+    }
+
+    /// Returns the dominant 7th on the lowered 7th of `target`, like "Bb7" before "C".
+    pub fn backdoor(&self, target: &Chord<Tone>) -> Chord<Tone> {
+        let root = (usize::from(*target.root()) + 10) % 12;
+        Chord::new(
+            Tone::spell(root, AccidentalSymbol::Flat),
+            Quality::Dominant7,
+        )
+    }
+
+    /// Returns the ii7 and `dominant` in order, where the ii7 is half-diminished if
+    /// `dominant` resolves to a minor triad of the key.
+    /// - Returns `None` if `dominant` has no major 3rd and minor 7th.
+    pub fn related_two(&self, dominant: &Chord<Tone>) -> Option<Vec<Chord<Tone>>> {
+        if !is_dominant(dominant) {
+            return None;
+        }
+        let target = Chord::new(spell(dominant.root(), 5), Quality::Major);
+        let quality = match self.triad(self.degree(target.root())) {
+            Some(Quality::Minor) => Quality::HalfDim7,
+            _ => Quality::Minor7,
+        };
+        let two = Chord::new(spell(dominant.root(), 7), quality);
+        Some(vec![two, *dominant])
+    }
+
+    /// Returns chords on the degree of `chord` in the parallel modes of the key, like
+    /// "Fm" and "Fm7" for "F" in C major.
+    /// - Triads are returned for a triad, and 7th chords otherwise.
+    /// - Returns an empty vector if the root of `chord` is not in the key.
+    pub fn borrowed(&self, chord: &Chord<Tone>) -> Vec<Chord<Tone>> {
+        let Some(degree) = self.degree(chord.root()) else {
+            return vec![];
+        };
+        let harmonization = match chord.quality().intervals().len() {
+            ..=2 => Harmonization::triads(),
+            _ => Harmonization::sevenths(),
+        };
+        let mut chords: Vec<Chord<Tone>> = vec![];
+        for kind in MODES {
+            let mode = Formula::named(self.key.key(), kind);
+            let Some(borrowed) = Self::chord(&mode, degree, harmonization) else {
+                continue;
+            };
+            if Self::chord(&self.key, degree, harmonization).as_ref() != Some(&borrowed)
+                && !chords.contains(&borrowed)
+            {
+                chords.push(borrowed);
+            }
+        }
+        chords
+    }
+
+    /// Returns every suggestion for `chord`, with the chords that lead to it first.
+    pub fn suggest(&self, chord: &Chord<Tone>) -> Vec<Suggestion> {
+        let suggestion = |substitution, chords| Suggestion {
+            substitution,
+            chords,
+        };
+        let mut suggestions = vec![];
+        if let Some(dominant) = self.secondary_dominant(chord) {
+            suggestions.push(suggestion(Substitution::SecondaryDominant, vec![dominant]));
+        }
+        if let Some(leading) = self.secondary_leading_tone(chord) {
+            suggestions.push(suggestion(
+                Substitution::SecondaryLeadingTone,
+                vec![leading],
+            ));
+        }
+        suggestions.push(suggestion(
+            Substitution::Backdoor,
+            vec![self.backdoor(chord)],
+        ));
+        if let Some(substitute) = self.tritone_substitute(chord) {
+            suggestions.push(suggestion(
+                Substitution::TritoneSubstitute,
+                vec![substitute],
+            ));
+        }
+        if let Some(chords) = self.related_two(chord) {
+            suggestions.push(suggestion(Substitution::RelatedTwo, chords));
+        }
+        for borrowed in self.borrowed(chord) {
+            suggestions.push(suggestion(Substitution::Borrowed, vec![borrowed]));
+        }
+        suggestions
+    }
+
+    /// Returns the quality of the triad on `target` if it is a major or minor triad of
+    /// the key other than the tonic, and `target` is that triad or the 7th chord of the key
+    /// on its degree, like "Dm" or "Dm7" in C major.
+    fn tonicizable(&self, target: &Chord<Tone>) -> Option<Quality> {
+        if !target.tensions().is_empty() {
+            return None;
+        }
+        let degree = self.degree(target.root()).filter(|d| d.value() != 1)?;
+        let triad = self
+            .triad(Some(degree))
+            .filter(|q| matches!(q, Quality::Major | Quality::Minor))?;
+        let seventh =
+            Self::chord(&self.key, degree, Harmonization::sevenths()).map(|c| c.quality());
+        (target.quality() == triad || Some(target.quality()) == seventh).then_some(triad)
+    }
+
+    /// Returns a degree of `tone` in the key.
+    fn degree(&self, tone: &Tone) -> Option<Degree> {
+        let tonic = usize::from(*self.key.key());
+        let distance = (usize::from(*tone) + 12 - tonic) % 12;
+        let index = self.key.distances().iter().position(|d| *d == distance)?;
+        Degree::new(index + 1).ok()
+    }
+
+    fn triad(&self, degree: Option<Degree>) -> Option<Quality> {
+        Self::chord(&self.key, degree?, Harmonization::triads()).map(|c| c.quality())
+    }
+
+    /// Returns the chord on `degree` of `mode`, spelled along its key signature.
+    fn chord(mode: &Formula, degree: Degree, harmonization: Harmonization) -> Option<Chord<Tone>> {
+        let keyboard = Keyboard::new(Scaled::new(mode.clone(), Twelve));
+        let intervals: Vec<Interval> = harmonization
+            .intervals(&keyboard, degree)
+            .into_iter()
+            .map(Interval::from)
+            .collect();
+        let accidental = KeySignature::from_mode(mode)
+            .unwrap_or_else(|| KeySignature::from_major(mode.key()))
+            .accidental();
+        let distance = mode.distances()[degree.value() - 1];
+        let root = Tone::spell((usize::from(*mode.key()) + distance) % 12, accidental);
+        Chord::from_intervals(root, &intervals)
+    }
+}
+
+/// Returns `true` if `chord` has a major 3rd and a minor 7th.
+fn is_dominant(chord: &Chord<Tone>) -> bool {
+    let intervals = chord.quality().intervals();
+    let has = |semitones: usize| intervals.iter().any(|i| i.value() == semitones);
+    has(4) && has(10)
+}
+
+/// Returns a tone `semitones` above `tone`, spelled with flats after a flat.
+fn spell(tone: &Tone, semitones: usize) -> Tone {
+    let accidental = match tone.accidental() {
+        AccidentalSymbol::Flat => AccidentalSymbol::Flat,
+        _ => AccidentalSymbol::Sharp,
+    };
+    Tone::spell((usize::from(*tone) + semitones) % 12, accidental)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn chord(symbol: &str) -> Chord<Tone> {
+        symbol.parse().unwrap()
+    }
+
+    fn reharmonizer(key: &str) -> Reharmonizer {
+        Reharmonizer::new(&Diatonic::major(&key.parse().unwrap()))
+    }
+
+    #[test]
+    fn secondary() {
+        let c = reharmonizer("C");
+        assert_eq!(c.secondary_dominant(&chord("Dm")), Some(chord("A7")));
+        assert_eq!(c.secondary_dominant(&chord("G")), Some(chord("D7")));
+        assert_eq!(c.secondary_dominant(&chord("C")), None);
+        assert_eq!(c.secondary_dominant(&chord("Bdim")), None);
+        assert_eq!(c.secondary_dominant(&chord("Dm7")), Some(chord("A7")));
+        assert_eq!(c.secondary_dominant(&chord("D")), None);
+        assert_eq!(c.secondary_dominant(&chord("Dmaj7")), None);
+        assert_eq!(c.secondary_dominant(&chord("Dsus4")), None);
+        assert_eq!(c.secondary_dominant(&chord("Dm(add9)")), None);
+        assert_eq!(c.secondary_leading_tone(&chord("D")), None);
+        assert_eq!(c.secondary_leading_tone(&chord("Em7b5")), None);
+        assert_eq!(
+            c.secondary_leading_tone(&chord("Dm")),
+            Some(chord("C#dim7"))
+        );
+        assert_eq!(c.secondary_leading_tone(&chord("G")), Some(chord("F#m7b5")));
+        let bb = reharmonizer("Bb");
+        assert_eq!(bb.secondary_dominant(&chord("Eb")), Some(chord("Bb7")));
+        assert_eq!(
+            bb.secondary_leading_tone(&chord("Eb")),
+            Some(chord("Dm7b5"))
+        );
+    }
+
+    #[test]
+    fn dominants() {
+        let c = reharmonizer("C");
+        assert_eq!(c.tritone_substitute(&chord("G7")), Some(chord("Db7")));
+        assert_eq!(c.tritone_substitute(&chord("Dm7")), None);
+        assert_eq!(c.backdoor(&chord("C")), chord("Bb7"));
+        assert_eq!(
+            c.related_two(&chord("G7")),
+            Some(vec![chord("Dm7"), chord("G7")])
+        );
+        assert_eq!(
+            c.related_two(&chord("E7")),
+            Some(vec![chord("Bm7b5"), chord("E7")])
+        );
+    }
+
+    #[test]
+    fn borrowed() {
+        let c = reharmonizer("C");
+        let borrowed = c.borrowed(&chord("F"));
+        assert!(borrowed.contains(&chord("Fm")));
+        assert!(!borrowed.contains(&chord("F")));
+        assert!(c.borrowed(&chord("Fmaj7")).contains(&chord("Fm7")));
+        assert!(c.borrowed(&chord("Ab")).is_empty());
+        let a = Reharmonizer::new(&Diatonic::minor(&"A".parse().unwrap()));
+        assert!(a.borrowed(&chord("Dm")).contains(&chord("D")));
+    }
+
+    #[test]
+    fn suggest() {
+        let suggestions = reharmonizer("C").suggest(&chord("G7"));
+        let kinds: Vec<Substitution> = suggestions.iter().map(|s| s.substitution()).collect();
+        assert!(kinds.contains(&Substitution::SecondaryDominant));
+        assert!(kinds.contains(&Substitution::TritoneSubstitute));
+        assert!(kinds.contains(&Substitution::RelatedTwo));
+        assert_eq!(suggestions[0].chords(), &[chord("D7")]);
+    }
+}
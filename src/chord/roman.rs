@@ -0,0 +1,414 @@
+use std::fmt::Display;
+
+use crate::{
+    chord::harmonize::spell_on,
+    core::Chord as CoreChord,
+    prelude::{
+        AccidentalSymbol, AlteredDegree, Chord, Degree, Formula, KeySignature, Number, Quality,
+        ScaleKind, Tension, Tone, ToneSymbol,
+    },
+};
+
+/// Chromatic chords that Roman numerals name specially.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Special {
+    /// The major triad on the lowered 2nd, like "N6".
+    Neapolitan,
+    /// The augmented sixth of the lowered 6th, the tonic and the raised 4th, like "It+6".
+    Italian,
+    /// The Italian sixth with the 2nd, like "Fr+6".
+    French,
+    /// The Italian sixth with the lowered 3rd, like "Ger+6".
+    German,
+}
+
+/// A chord written as a Roman numeral relative to a key, like "ii7", "V7/V" or "bVI".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RomanNumeral {
+    degree: AlteredDegree,
+    quality: Quality,
+    inversion: usize,
+    special: Option<Special>,
+    secondary: Option<Box<RomanNumeral>>,
+}
+
+/// Qualities that Roman numerals can describe.
+const QUALITIES: [Quality; 15] = {
+    use Quality::*;
+    [
+        Major,
+        Minor,
+        Dim,
+        Aug,
+        Dominant7,
+        Major7,
+        Minor7,
+        HalfDim7,
+        Dim7,
+        MinorMajor7,
+        Aug7,
+        AugMajor7,
+        Dominant9,
+        Major9,
+        Minor9,
+    ]
+};
+
+impl RomanNumeral {
+    /// Returns a `RomanNumeral` of `quality` on `degree`.
+    /// - `inversion` is an index of the bass from the root among the chord tones.
+    /// # Panics
+    /// - if a ninth chord is inverted, which figures do not describe.
+    pub fn new(degree: AlteredDegree, quality: Quality, inversion: usize) -> Self {
+        assert!(
+            quality.intervals().len() < 4 || inversion == 0,
+            "inverted ninth chord"
+        );
+        Self {
+            degree,
+            quality,
+            inversion,
+            special: None,
+            secondary: None,
+        }
+    }
+
+    /// Returns a `RomanNumeral` of a special chord, like "N6" or "Ger+6".
+    pub fn special(special: Special) -> Self {
+        let (degree, quality, inversion) = match special {
+            Special::Neapolitan => (2, Quality::Major, 1),
+            _ => (6, Quality::Dominant7, 0),
+        };
+        let degree = AlteredDegree::new(Degree::new(degree).unwrap(), -1);
+        Self {
+            special: Some(special),
+            ..Self::new(degree, quality, inversion)
+        }
+    }
+
+    /// Returns a secondary chord that tonicizes `target`, like "V/V".
+    pub fn of(self, target: RomanNumeral) -> Self {
+        Self {
+            secondary: Some(Box::new(target)),
+            ..self
+        }
+    }
+
+    pub fn degree(&self) -> AlteredDegree {
+        self.degree
+    }
+
+    pub fn quality(&self) -> Quality {
+        self.quality
+    }
+
+    pub fn inversion(&self) -> usize {
+        self.inversion
+    }
+
+    pub fn get_special(&self) -> Option<Special> {
+        self.special
+    }
+
+    /// Returns the chord that this chord tonicizes, like "V" of "V/V".
+    pub fn secondary(&self) -> Option<&RomanNumeral> {
+        self.secondary.as_deref()
+    }
+
+    /// Returns a `Chord` that this numeral points in `key`.
+    /// - Accidentals are relative to the degrees of `key`, and a secondary chord is
+    ///   relative to the major scale on its target.
+    /// - Augmented sixths are named as the dominant 7th on the lowered 6th, which
+    ///   they sound as. The French sixth has "b5", and the Italian sixth, which lacks
+    ///   its 5th, is named as the German sixth; `to_class` tells them apart.
+    /// - Returns `None` if the degree is out of `key`.
+    pub fn to_chord(&self, key: &Formula) -> Option<Chord<Tone>> {
+        let key = match &self.secondary {
+            Some(target) => {
+                let root = *target.to_chord(key)?.root();
+                Formula::named(&root, ScaleKind::Major)
+            }
+            None => key.clone(),
+        };
+        let distances = key.distances();
+        let index = Number::value(&self.degree.degree());
+        let distance = *distances.get(index % distances.len())? as i32
+            + 12 * (index / distances.len()) as i32
+            + self.degree.alteration();
+        let tonic = usize::from(*key.key()) as i32;
+        let accidental = match self.degree.alteration().signum() {
+            -1 => AccidentalSymbol::Flat,
+            1 => AccidentalSymbol::Sharp,
+            _ => KeySignature::from_mode(&key)
+                .unwrap_or_else(|| KeySignature::from_major(key.key()))
+                .accidental(),
+        };
+        let class = (tonic + distance).rem_euclid(12) as usize;
+        // A degree of a heptatonic key is spelled on its letter, like "Cb" for IV in Gb.
+        let root = match distances.len() {
+            7 => {
+                use ToneSymbol::*;
+                let letters = [C, D, E, F, G, A, B];
+                let from = letters.iter().position(|l| l == key.key().tone()).unwrap();
+                spell_on(letters[(from + index) % 7], class, accidental)
+            }
+            _ => Tone::spell(class, accidental),
+        };
+        let chord = Chord::new(root, self.quality);
+        let chord = match self.special {
+            Some(Special::French) => chord.with_tension(Tension::FlatFive),
+            _ => chord,
+        };
+        Some(chord.with_inversion(self.inversion))
+    }
+
+    /// Returns tones that this numeral sounds in `key`, from the root.
+    /// - The Italian sixth omits the 5th of `to_chord`, like "Ab", "C" and "F#" in C.
+    /// - Returns `None` if the degree is out of `key`.
+    pub fn to_class(&self, key: &Formula) -> Option<CoreChord<Tone>> {
+        let tones = self.to_chord(key)?.into_class();
+        if self.special != Some(Special::Italian) {
+            return Some(tones);
+        }
+        let fifth = (usize::from(*tones.root()) + 7) % 12;
+        let others = tones
+            .others()
+            .iter()
+            .filter(|t| usize::from(**t) % 12 != fifth);
+        Some(CoreChord::new(*tones.root(), others.copied().collect()))
+    }
+
+    /// Returns a `RomanNumeral` of `chord` in `key`.
+    /// - A chromatic dominant or leading-tone chord is analysed as a secondary chord
+    ///   if it tonicizes a major or minor triad of `key`.
+    /// - Returns `None` if the chord has tensions, a bass that is not a chord tone, or a
+    ///   quality that Roman numerals do not describe.
+    /// - Returns `None` for an inverted ninth chord, which figures do not describe.
+    pub fn analyze(chord: &Chord<Tone>, key: &Formula) -> Option<Self> {
+        if !chord.tensions().is_empty() || !QUALITIES.contains(&chord.quality()) {
+            return None;
+        }
+        let inversion = chord.inversion()?;
+        if chord.quality().intervals().len() >= 4 && inversion != 0 {
+            return None;
+        }
+        let degree = Self::degree_of(chord.root(), key);
+        let numeral = Self::new(degree, chord.quality(), inversion);
+        if numeral.quality == Quality::Major
+            && degree == AlteredDegree::new(Degree::new(2).unwrap(), -1)
+            && inversion == 1
+            && key.distances().len() == 7
+        {
+            return Some(Self::special(Special::Neapolitan));
+        }
+        if degree.alteration() == 0 && Self::is_diatonic(chord, key) {
+            return Some(numeral);
+        }
+
+        use Quality::*;
+        let root = usize::from(*chord.root());
+        let (function, target) = match chord.quality() {
+            Major | Dominant7 | Dominant9 => (5, root + 5),
+            Dim | Dim7 | HalfDim7 => (7, root + 1),
+            _ => return Some(numeral),
+        };
+        let target = Tone::spell(target % 12, *chord.root().accidental());
+        let target_degree = Self::degree_of(&target, key);
+        let triad = Self::triad(target_degree, key);
+        let target_quality = match triad {
+            Some(Major) | Some(Minor) if target_degree.degree() != Degree::new(1).unwrap() => {
+                triad.unwrap()
+            }
+            _ => return Some(numeral),
+        };
+        let function = AlteredDegree::natural(Degree::new(function).unwrap());
+        let target = Self::new(target_degree, target_quality, 0);
+        Some(Self::new(function, chord.quality(), inversion).of(target))
+    }
+
+    /// Returns a degree of `tone` in `key`, which is lowered from the next degree
+    /// unless `tone` is spelled with a sharp.
+    fn degree_of(tone: &Tone, key: &Formula) -> AlteredDegree {
+        let tonic = usize::from(*key.key());
+        let distance = (usize::from(*tone) + 12 - tonic) % 12;
+        let distances = key.distances();
+        let degree = |i: usize| Degree::new(i + 1).unwrap();
+        if let Some(i) = distances.iter().position(|d| *d == distance) {
+            return AlteredDegree::natural(degree(i));
+        }
+        let sharp = distances.iter().rposition(|d| *d < distance);
+        let flat = distances.iter().position(|d| *d > distance);
+        match (sharp, flat, tone.accidental()) {
+            (Some(i), _, AccidentalSymbol::Sharp) | (Some(i), None, _) => {
+                AlteredDegree::new(degree(i), (distance - distances[i]) as i32)
+            }
+            (_, Some(i), _) => AlteredDegree::new(degree(i), distance as i32 - distances[i] as i32),
+            (None, None, _) => AlteredDegree::natural(degree(0)),
+        }
+    }
+
+    /// Returns a quality of the triad that stacks thirds of `key` on `degree`.
+    fn triad(degree: AlteredDegree, key: &Formula) -> Option<Quality> {
+        if degree.alteration() != 0 {
+            return None;
+        }
+        let distances = key.distances();
+        let len = distances.len();
+        let index = Number::value(&degree.degree());
+        let interval = |k: usize| {
+            let i = index + k;
+            (distances[i % len] + 12 * (i / len) - distances[index % len]).into()
+        };
+        Quality::from_intervals(&[interval(2), interval(4)])
+    }
+
+    fn is_diatonic(chord: &Chord<Tone>, key: &Formula) -> bool {
+        let tonic = usize::from(*key.key());
+        chord.into_class().into_vec().iter().all(|tone| {
+            let distance = (usize::from(*tone) + 12 - tonic) % 12;
+            key.distances().contains(&distance)
+        })
+    }
+}
+
+/// Writes a Roman numeral like "bVI", "viiø65" or "V7/V".
+impl Display for RomanNumeral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Quality::*;
+        if let Some(special) = self.special {
+            let symbol = match special {
+                Special::Neapolitan => "N",
+                Special::Italian => "It+",
+                Special::French => "Fr+",
+                Special::German => "Ger+",
+            };
+            let figure = match (special, self.inversion) {
+                (Special::Neapolitan, 0) => "",
+                _ => "6",
+            };
+            return write!(f, "{symbol}{figure}");
+        }
+        let accidental = match self.degree.alteration() {
+            a if a < 0 => "b".repeat(a.unsigned_abs() as usize),
+            a => "#".repeat(a as usize),
+        };
+        const NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+        let numeral = NUMERALS[Number::value(&self.degree.degree()) % 7];
+        let numeral = match self.quality {
+            Minor | Dim | Minor7 | HalfDim7 | Dim7 | MinorMajor7 | Minor9 => numeral.to_lowercase(),
+            _ => numeral.to_string(),
+        };
+        let symbol = match self.quality {
+            Dim | Dim7 => "°",
+            HalfDim7 => "ø",
+            Aug | Aug7 => "+",
+            AugMajor7 => "+M",
+            Major7 | MinorMajor7 | Major9 => "M",
+            _ => "",
+        };
+        let figure = match (self.quality.intervals().len(), self.inversion) {
+            (2, 1) => "6",
+            (2, 2) => "64",
+            (3, 0) => "7",
+            (3, 1) => "65",
+            (3, 2) => "43",
+            (3, 3) => "42",
+            (4, 0) => "9",
+            _ => "",
+        };
+        write!(f, "{accidental}{numeral}{symbol}{figure}")?;
+        match &self.secondary {
+            Some(target) => write!(f, "/{target}"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn c_major() -> Formula {
+        Formula::from(&Diatonic::major(&"C".parse().unwrap()))
+    }
+
+    fn chord(numeral: &str, key: &Formula) -> Chord<Tone> {
+        numeral
+            .parse::<RomanNumeral>()
+            .unwrap()
+            .to_chord(key)
+            .unwrap()
+    }
+
+    fn symbol(symbol: &str) -> Chord<Tone> {
+        symbol.parse().unwrap()
+    }
+
+    #[test]
+    fn to_chord() {
+        let key = c_major();
+        let progression: Vec<Chord<Tone>> = "I vi ii7 V7"
+            .split_whitespace()
+            .map(|n| chord(n, &key))
+            .collect();
+        assert_eq!(progression, ["C", "Am", "Dm7", "G7"].map(symbol).to_vec());
+        assert_eq!(chord("V7/V", &key), symbol("D7"));
+        assert_eq!(chord("vii°7/ii", &key), symbol("C#dim7"));
+        assert_eq!(chord("bVI", &key), symbol("Ab"));
+        assert_eq!(chord("viiø7", &key), symbol("Bm7b5"));
+        assert_eq!(chord("V65", &key), symbol("G7/B"));
+        assert_eq!(chord("I64", &key), symbol("C/G"));
+        assert_eq!(chord("N6", &key), symbol("Db/F"));
+        assert_eq!(chord("It+6", &key), symbol("Ab7"));
+        let gb_major = Formula::from(&Diatonic::major(&"Gb".parse().unwrap()));
+        assert_eq!(chord("IV", &gb_major), symbol("Cb"));
+        assert_eq!(chord("V7/IV", &gb_major), symbol("Gb7"));
+        assert_eq!(chord("#iv°", &key), symbol("F#dim"));
+        let tones = |numeral: &str| {
+            let numeral: RomanNumeral = numeral.parse().unwrap();
+            numeral.to_class(&key).unwrap().into_vec()
+        };
+        assert_eq!(tones("It+6"), ["Ab", "C", "F#"].map(|t| t.parse().unwrap()));
+        assert_eq!(tones("Ger+6").len(), 4);
+        assert_eq!(chord("Fr+6", &key), symbol("Ab7(b5)"));
+        let minor = Formula::from(&Diatonic::minor(&"A".parse().unwrap()));
+        assert_eq!(chord("iv", &minor), symbol("Dm"));
+        assert_eq!(chord("VI", &minor), symbol("F"));
+        let dorian = Formula::named(&"D".parse().unwrap(), ScaleKind::Dorian);
+        assert_eq!(chord("IV7", &dorian), symbol("G7"));
+    }
+
+    #[test]
+    fn analyze() {
+        let key = c_major();
+        let analyze = |s: &str| RomanNumeral::analyze(&symbol(s), &key).unwrap().to_string();
+        assert_eq!(analyze("Dm7"), "ii7");
+        assert_eq!(analyze("G7/B"), "V65");
+        assert_eq!(analyze("Bm7b5/F"), "viiø43");
+        assert_eq!(analyze("D7"), "V7/V");
+        assert_eq!(analyze("C#dim7"), "vii°7/ii");
+        assert_eq!(analyze("Ab"), "bVI");
+        assert_eq!(analyze("F#dim"), "vii°/V");
+        assert_eq!(analyze("Db/F"), "N6");
+        assert_eq!(analyze("Cmaj7"), "IM7");
+        assert_eq!(RomanNumeral::analyze(&symbol("C7(b9)"), &key), None);
+        assert_eq!(analyze("G9"), "V9");
+        assert_eq!(RomanNumeral::analyze(&symbol("G9/B"), &key), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = c_major();
+        for numeral in [
+            "ii7", "V7/V", "bVI", "viiø7", "It+6", "N6", "V65", "iii64", "IV+",
+        ] {
+            let parsed: RomanNumeral = numeral.parse().unwrap();
+            assert_eq!(parsed.to_string(), numeral);
+            let chord = parsed.to_chord(&key).unwrap();
+            if parsed.get_special().is_none() || numeral == "N6" {
+                let analyzed = RomanNumeral::analyze(&chord, &key).unwrap();
+                assert_eq!(analyzed.to_chord(&key), Some(chord), "{numeral}");
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::core::Interval;
+
+/// An extension or an alteration that put on a chord, like "b9" or "#11".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tension {
+    FlatFive,
+    SharpFive,
+    FlatNine,
+    Nine,
+    SharpNine,
+    Eleven,
+    SharpEleven,
+    FlatThirteen,
+    Thirteen,
+}
+
+static ENUM_TENSION: Lazy<[Tension; 9]> = Lazy::new(|| {
+    use Tension::*;
+    [
+        FlatFive,
+        SharpFive,
+        FlatNine,
+        Nine,
+        SharpNine,
+        Eleven,
+        SharpEleven,
+        FlatThirteen,
+        Thirteen,
+    ]
+});
+
+/// Pairs of the interval of a tension and the interval that the tension replaces.
+static TENSION_TO_INTERVAL: Lazy<HashMap<Tension, (Interval, Option<Interval>)>> =
+    Lazy::new(|| {
+        use Tension::*;
+        let mut hash = HashMap::<Tension, (Interval, Option<Interval>)>::default();
+        hash.insert(FlatFive, (6.into(), Some(7.into())));
+        hash.insert(SharpFive, (8.into(), Some(7.into())));
+        hash.insert(FlatNine, (13.into(), Some(14.into())));
+        hash.insert(Nine, (14.into(), None));
+        hash.insert(SharpNine, (15.into(), Some(14.into())));
+        hash.insert(Eleven, (17.into(), None));
+        hash.insert(SharpEleven, (18.into(), Some(17.into())));
+        hash.insert(FlatThirteen, (20.into(), Some(21.into())));
+        hash.insert(Thirteen, (21.into(), None));
+        hash
+    });
+
+impl Tension {
+    pub fn enumerate() -> std::slice::Iter<'static, Tension> {
+        ENUM_TENSION.iter()
+    }
+
+    /// Returns an interval from the root.
+    pub fn interval(&self) -> Interval {
+        TENSION_TO_INTERVAL.get(self).unwrap().0
+    }
+
+    /// Returns an interval of the chord tone that this tension alters, like the 5th for "b5".
+    /// - Returns `None` for an unaltered extension like "9".
+    pub fn replaces(&self) -> Option<Interval> {
+        TENSION_TO_INTERVAL.get(self).unwrap().1
+    }
+}
+
+/// A set of `Tension`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Tensions(u16);
+
+impl Tensions {
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    pub fn insert(&mut self, tension: Tension) {
+        self.0 |= 1 << tension as u16;
+    }
+
+    pub fn remove(&mut self, tension: Tension) {
+        self.0 &= !(1 << tension as u16);
+    }
+
+    pub fn with(mut self, tension: Tension) -> Self {
+        self.insert(tension);
+        self
+    }
+
+    pub fn contains(&self, tension: Tension) -> bool {
+        self.0 & (1 << tension as u16) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns an iterator over tensions in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Tension> {
+        let tensions = *self;
+        Tension::enumerate()
+            .copied()
+            .filter(move |t| tensions.contains(*t))
+    }
+}
+
+impl FromIterator<Tension> for Tensions {
+    fn from_iter<T: IntoIterator<Item = Tension>>(iter: T) -> Self {
+        let mut tensions = Tensions::new();
+        iter.into_iter().for_each(|t| tensions.insert(t));
+        tensions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tension, Tensions};
+
+    #[test]
+    fn tensions() {
+        let mut tensions: Tensions = [Tension::SharpEleven, Tension::FlatNine]
+            .into_iter()
+            .collect();
+        assert_eq!(tensions.len(), 2);
+        assert!(tensions.contains(Tension::FlatNine));
+        assert!(!tensions.contains(Tension::Nine));
+        let mut iter = tensions.iter();
+        assert_eq!(iter.next(), Some(Tension::FlatNine));
+        assert_eq!(iter.next(), Some(Tension::SharpEleven));
+        assert_eq!(iter.next(), None);
+        tensions.remove(Tension::FlatNine);
+        assert_eq!(tensions, Tensions::new().with(Tension::SharpEleven));
+    }
+}
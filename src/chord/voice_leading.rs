@@ -0,0 +1,281 @@
+use crate::prelude::{Chord, Pitch, PitchRange, Tone, Voicer, Voicing};
+
+use super::voicing::{class, number};
+
+/// Returns a total distance in semitones that each voice moves from `from` to `to`.
+/// - Voices are paired in order, like from the lowest.
+/// - Returns `None` if the numbers of voices are different.
+pub fn voice_leading_distance(from: &[Pitch<Tone>], to: &[Pitch<Tone>]) -> Option<usize> {
+    (from.len() == to.len()).then(|| {
+        from.iter()
+            .zip(to)
+            .map(|(a, b)| number(a).abs_diff(number(b)))
+            .sum()
+    })
+}
+
+/// Finds voicings of successive chords that minimise the total voice movement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceLeading {
+    ranges: Vec<PitchRange<Tone>>,
+    crossing: bool,
+    parallels: bool,
+}
+
+impl VoiceLeading {
+    /// Returns a solver for `voices` voices.
+    /// - 4 voices get the ranges of bass, tenor, alto and soprano, and the others
+    ///   share a range from C3 to C5.
+    pub fn new(voices: usize) -> Self {
+        let range = |low: (&str, usize), high: (&str, usize)| {
+            let pitch = |(tone, oct): (&str, usize)| Pitch::new(tone.parse().unwrap(), oct);
+            PitchRange::new(pitch(low), pitch(high))
+        };
+        let ranges = match voices {
+            4 => vec![
+                range(("E", 2), ("C", 4)),
+                range(("C", 3), ("G", 4)),
+                range(("G", 3), ("C", 5)),
+                range(("C", 4), ("G", 5)),
+            ],
+            _ => vec![range(("C", 3), ("C", 5)); voices],
+        };
+        Self::with_ranges(ranges)
+    }
+
+    /// Returns a solver whose voices have given ranges, from the lowest voice.
+    pub fn with_ranges(ranges: Vec<PitchRange<Tone>>) -> Self {
+        Self {
+            ranges,
+            crossing: false,
+            parallels: false,
+        }
+    }
+
+    /// Allows a voice to go above the next higher voice.
+    pub fn allow_crossing(self, crossing: bool) -> Self {
+        Self { crossing, ..self }
+    }
+
+    /// Allows parallel fifths and octaves between any two voices.
+    pub fn allow_parallels(self, parallels: bool) -> Self {
+        Self { parallels, ..self }
+    }
+
+    pub fn voices(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns pitches of each voice from the lowest for each chord.
+    /// - The lowest voice takes the bass of a slash chord, or the root otherwise.
+    /// - Adjacent upper voices stay within an octave.
+    /// - Returns `None` if no voicing satisfies the ranges and the rules.
+    pub fn solve(&self, chords: &[Chord<Tone>]) -> Option<Vec<Vec<Pitch<Tone>>>> {
+        let candidates: Vec<Vec<Vec<Pitch<Tone>>>> =
+            chords.iter().map(|c| self.candidates(c)).collect();
+        let first = candidates.first()?;
+        // `costs[i]` is the minimum distance to reach the i-th candidate, and the previous one.
+        let mut costs: Vec<Vec<(usize, usize)>> = vec![vec![(0, 0); first.len()]];
+        for k in 1..candidates.len() {
+            let previous = &costs[k - 1];
+            let cost = candidates[k]
+                .iter()
+                .map(|to| {
+                    candidates[k - 1]
+                        .iter()
+                        .zip(previous)
+                        .enumerate()
+                        .filter(|(_, (_, (cost, _)))| *cost != usize::MAX)
+                        .filter(|(_, (from, _))| self.parallels || !has_parallels(from, to))
+                        .map(|(i, (from, (cost, _)))| {
+                            (cost + voice_leading_distance(from, to).unwrap(), i)
+                        })
+                        .min()
+                        .unwrap_or((usize::MAX, 0))
+                })
+                .collect();
+            costs.push(cost);
+        }
+
+        let (mut index, _) = costs
+            .last()?
+            .iter()
+            .enumerate()
+            .filter(|(_, (cost, _))| *cost != usize::MAX)
+            .min_by_key(|(_, (cost, _))| *cost)?;
+        let mut voicings = vec![];
+        for k in (0..candidates.len()).rev() {
+            voicings.push(candidates[k][index].clone());
+            index = costs[k][index].1;
+        }
+        voicings.reverse();
+        Some(voicings)
+    }
+
+    /// Returns voicings of `chord` that sound every selected chord tone with the spacing.
+    fn candidates(&self, chord: &Chord<Tone>) -> Vec<Vec<Pitch<Tone>>> {
+        let voices = self.voices();
+        let tones: Vec<Tone> = Voicer::new(Voicing::Close)
+            .with_voices(voices.saturating_sub(chord.bass().is_some() as usize))
+            .tones(chord)
+            .into_iter()
+            .map(|(_, t)| t)
+            .collect();
+        let bass = chord.bass().copied().unwrap_or(*chord.root());
+        let mut required: Vec<usize> = tones.iter().map(class).collect();
+        required.push(class(&bass));
+        required.sort();
+        required.dedup();
+
+        let pitches: Vec<Vec<Pitch<Tone>>> = self
+            .ranges
+            .iter()
+            .enumerate()
+            .map(|(i, range)| {
+                (number(range.low())..=number(range.high()))
+                    .filter_map(|n| {
+                        let tone = match i {
+                            0 => Some(&bass).filter(|b| class(b) == n % 12),
+                            _ => tones.iter().find(|t| class(t) == n % 12),
+                        };
+                        tone.map(|t| Pitch::new(*t, n / 12))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut candidates = vec![];
+        let mut voicing = vec![];
+        self.collect(&pitches, &required, &mut voicing, &mut candidates);
+        candidates
+    }
+
+    fn collect(
+        &self,
+        pitches: &[Vec<Pitch<Tone>>],
+        required: &[usize],
+        voicing: &mut Vec<Pitch<Tone>>,
+        candidates: &mut Vec<Vec<Pitch<Tone>>>,
+    ) {
+        // Stops early if the remaining voices cannot sound every missing chord tone.
+        let missing = required
+            .iter()
+            .filter(|r| !voicing.iter().any(|p| class(p.class()) == **r))
+            .count();
+        if missing > pitches.len() - voicing.len() {
+            return;
+        }
+        let Some(choices) = pitches.get(voicing.len()) else {
+            candidates.push(voicing.clone());
+            return;
+        };
+        for pitch in choices {
+            // The bass stays the lowest even if crossing is allowed.
+            let lower = voicing.last().map(number).unwrap_or(0);
+            let bass = voicing.first().map(number).unwrap_or(0);
+            if (!self.crossing && number(pitch) < lower) || number(pitch) < bass {
+                continue;
+            }
+            // Adjacent upper voices stay within an octave.
+            if voicing.len() >= 2 && number(pitch).abs_diff(lower) > 12 {
+                continue;
+            }
+            voicing.push(*pitch);
+            self.collect(pitches, required, voicing, candidates);
+            voicing.pop();
+        }
+    }
+}
+
+/// Returns `true` if any two voices move in parallel fifths or octaves.
+fn has_parallels(from: &[Pitch<Tone>], to: &[Pitch<Tone>]) -> bool {
+    let perfect = |a: &Pitch<Tone>, b: &Pitch<Tone>| {
+        let interval = number(a).abs_diff(number(b)) % 12;
+        (interval == 0 || interval == 7).then_some(interval)
+    };
+    (0..from.len()).any(|i| {
+        (i + 1..from.len()).any(|j| {
+            let moved = from[i] != to[i] && from[j] != to[j];
+            let up = |voice: usize| number(&from[voice]) < number(&to[voice]);
+            let same = up(i) == up(j);
+            moved
+                && same
+                && perfect(&from[i], &from[j]).is_some()
+                && perfect(&from[i], &from[j]) == perfect(&to[i], &to[j])
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn pitches(pitches: &[&str]) -> Vec<Pitch<Tone>> {
+        pitches
+            .iter()
+            .map(|p| {
+                let (tone, oct) = p.split_at(p.len() - 1);
+                Pitch::new(tone.parse().unwrap(), oct.parse().unwrap())
+            })
+            .collect()
+    }
+
+    fn chords(symbols: &[&str]) -> Vec<Chord<Tone>> {
+        symbols.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn distance() {
+        let from = pitches(&["C3", "E4", "G4", "C5"]);
+        let to = pitches(&["F3", "F4", "A4", "C5"]);
+        assert_eq!(voice_leading_distance(&from, &to), Some(8));
+        assert_eq!(voice_leading_distance(&from, &to[..3]), None);
+    }
+
+    #[test]
+    fn solve() {
+        let solver = VoiceLeading::new(4);
+        let voicings = solver.solve(&chords(&["C", "F", "G7", "C"])).unwrap();
+        assert_eq!(voicings.len(), 4);
+        for (voicing, chord) in voicings.iter().zip(["C", "F", "G", "C"]) {
+            assert_eq!(voicing.len(), 4);
+            assert_eq!(voicing[0].class(), &chord.parse().unwrap());
+            let numbers: Vec<usize> = voicing
+                .iter()
+                .map(|p| p.oct() * 12 + usize::from(*p.class()))
+                .collect();
+            assert!(numbers.windows(2).all(|w| w[0] <= w[1]));
+            assert!(numbers[1..].windows(2).all(|w| w[1] - w[0] <= 12));
+        }
+        for pair in voicings.windows(2) {
+            for (from, to) in pair[0].iter().zip(&pair[1]).skip(1) {
+                // Upper voices move by small steps.
+                let distance = voice_leading_distance(&[*from], &[*to]).unwrap();
+                assert!(distance <= 5, "{from:?} -> {to:?}");
+            }
+            assert!(!super::has_parallels(&pair[0], &pair[1]));
+        }
+    }
+
+    #[test]
+    fn parallels() {
+        let from = pitches(&["C3", "G3"]);
+        let to = pitches(&["D3", "A3"]);
+        assert!(super::has_parallels(&from, &to));
+        let to = pitches(&["D3", "G3"]);
+        assert!(!super::has_parallels(&from, &to));
+        let range = |low, high| {
+            let p = pitches(&[low, high]);
+            PitchRange::new(p[0], p[1])
+        };
+        let solver = VoiceLeading::with_ranges(vec![range("C3", "C3"), range("G3", "G3")]);
+        assert_eq!(
+            solver.solve(&chords(&["C5", "C5"])).map(|v| v.len()),
+            Some(2)
+        );
+        let solver = VoiceLeading::with_ranges(vec![range("C3", "D3"), range("G3", "A3")]);
+        assert_eq!(solver.solve(&chords(&["C5", "D5"])), None);
+        let solver = solver.allow_parallels(true);
+        assert!(solver.solve(&chords(&["C5", "D5"])).is_some());
+    }
+}
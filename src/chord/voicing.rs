@@ -0,0 +1,352 @@
+use crate::{
+    core::Chord as CoreChord,
+    prelude::{Chord, Keyboard, Pitch, PitchRange, Tone, Twelve},
+};
+
+/// Ways of spreading chord tones over voices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Voicing {
+    /// All voices within an octave.
+    Close,
+    /// Every other voice of the close position raised an octave.
+    Open,
+    /// The second voice from the top of the close position dropped an octave.
+    Drop2,
+    /// The third voice from the top of the close position dropped an octave.
+    Drop3,
+    /// The second and the fourth voices from the top of the close position dropped an octave.
+    Drop24,
+    /// Only the root, the 3rd and the 7th.
+    Shell,
+    /// The lowest voice of the close position dropped an octave.
+    Spread,
+}
+
+/// Generates concrete voicings of `Chord`s in a `PitchRange`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Voicer {
+    voicing: Voicing,
+    voices: Option<usize>,
+    top: Option<Tone>,
+    bottom: Option<Tone>,
+}
+
+impl Voicer {
+    pub fn new(voicing: Voicing) -> Self {
+        Self {
+            voicing,
+            voices: None,
+            top: None,
+            bottom: None,
+        }
+    }
+
+    /// Sets a number of voices.
+    /// - Fewer voices omit the 5th first and then extensions, and more voices double
+    ///   chord tones from the root.
+    /// - Ignored by `Voicing::Shell`.
+    pub fn with_voices(self, voices: usize) -> Self {
+        Self {
+            voices: Some(voices),
+            ..self
+        }
+    }
+
+    /// Sets a tone of the highest voice.
+    pub fn with_top(self, top: Tone) -> Self {
+        Self {
+            top: Some(top),
+            ..self
+        }
+    }
+
+    /// Sets a tone of the lowest voice, which is the bass of a slash chord by default.
+    /// - A tone that is not a chord tone is added below the voicing.
+    pub fn with_bottom(self, bottom: Tone) -> Self {
+        Self {
+            bottom: Some(bottom),
+            ..self
+        }
+    }
+
+    pub fn voicing(&self) -> Voicing {
+        self.voicing
+    }
+
+    /// Returns voicings of `chord` that fit in `range`, from the lowest.
+    /// - A chord tone that is not sounded is not contained in the result.
+    pub fn voice(
+        &self,
+        chord: &Chord<Tone>,
+        range: &PitchRange<Tone>,
+    ) -> Vec<CoreChord<Pitch<Tone>>> {
+        let tones = self.tones(chord);
+        let bottom = self.bottom.or(chord.bass().copied());
+        let slash = bottom.filter(|b| !tones.iter().any(|(_, t)| class(t) == class(b)));
+        let mut shapes: Vec<Vec<(i32, Tone)>> = vec![];
+        for mut shape in self.shapes(&tones) {
+            shape.sort_by_key(|(offset, _)| *offset);
+            let (low, _) = shape[0];
+            match (bottom, slash) {
+                (_, Some(bass)) => {
+                    let below = (low - class(&bass) as i32).rem_euclid(12);
+                    shape.insert(0, (low - if below == 0 { 12 } else { below }, bass));
+                }
+                (Some(bass), None) if class(&shape[0].1) != class(&bass) => continue,
+                _ => {}
+            }
+            let highest = shape.last().unwrap().1;
+            if self.top.is_some_and(|top| class(&top) != class(&highest)) {
+                continue;
+            }
+            if !shapes.contains(&shape) {
+                shapes.push(shape);
+            }
+        }
+
+        let low = number(range.low());
+        let high = number(range.high());
+        let mut voicings = vec![];
+        for base in low..=high {
+            for shape in &shapes {
+                let (first, tone) = shape[0];
+                let (last, _) = shape[shape.len() - 1];
+                if base % 12 != class(&tone) || base + (last - first) as usize > high {
+                    continue;
+                }
+                let pitches = shape
+                    .iter()
+                    .map(|(offset, tone)| {
+                        let number = base + (offset - first) as usize;
+                        Pitch::new(*tone, number / 12)
+                    })
+                    .collect();
+                voicings.push(into_chord(pitches, chord.root(), slash.is_some()));
+            }
+        }
+        voicings
+    }
+
+    /// Returns intervals from the root and tones to be voiced, in ascending order.
+    pub(crate) fn tones(&self, chord: &Chord<Tone>) -> Vec<(usize, Tone)> {
+        let keyboard = Keyboard::new(Twelve);
+        let root: usize = (*chord.root()).into();
+        let mut tones: Vec<(usize, Tone)> = std::iter::once((0, *chord.root()))
+            .chain(
+                chord
+                    .intervals()
+                    .iter()
+                    .map(|i| (i.value(), *keyboard.get_class(&(root + i.value())))),
+            )
+            .collect();
+        let third = tones.iter().find(|(i, _)| (2..=5).contains(i)).map(|t| t.0);
+        let seventh = tones
+            .iter()
+            .rfind(|(i, _)| (9..=11).contains(i))
+            .map(|t| t.0);
+        let essential = |i: usize| i == 0 || Some(i) == third || Some(i) == seventh;
+
+        if self.voicing == Voicing::Shell {
+            let shell = seventh.or(Some(7));
+            tones.retain(|(i, _)| *i == 0 || Some(*i) == third || Some(*i) == shell);
+            return tones;
+        }
+        let voices = self.voices.unwrap_or(tones.len()).max(1);
+        while tones.len() > voices {
+            // The root is never omitted, so the 3rd and the 7th go last from the top.
+            let omitted = [7, 17, 14]
+                .into_iter()
+                .find(|i| tones.iter().any(|t| t.0 == *i))
+                .or_else(|| tones.iter().map(|t| t.0).rfind(|i| !essential(*i)))
+                .or_else(|| tones.iter().map(|t| t.0).rfind(|i| *i != 0));
+            let Some(omitted) = omitted else {
+                break;
+            };
+            tones.retain(|t| t.0 != omitted);
+        }
+        let count = tones.len();
+        for k in 0..voices.saturating_sub(count) {
+            let (interval, tone) = tones[k % count];
+            tones.push((interval + 12 * (1 + k / count), tone));
+        }
+        tones
+    }
+
+    /// Returns shapes as offsets from the root, which may be negative.
+    fn shapes(&self, tones: &[(usize, Tone)]) -> Vec<Vec<(i32, Tone)>> {
+        let len = tones.len();
+        if self.voicing == Voicing::Shell {
+            let mut upper = tones[1..].to_vec();
+            let mut shapes = vec![close(tones)];
+            upper.reverse();
+            shapes.push(close(&[&tones[..1], &upper].concat()));
+            return shapes;
+        }
+        let dropped: &[usize] = match self.voicing {
+            Voicing::Drop2 => &[2],
+            Voicing::Drop3 => &[3],
+            Voicing::Drop24 => &[2, 4],
+            _ => &[],
+        };
+        if dropped.iter().any(|d| *d > len) {
+            return vec![];
+        }
+        (0..len)
+            .map(|k| {
+                let mut order = tones.to_vec();
+                order.rotate_left(k);
+                let mut shape = close(&order);
+                for d in dropped {
+                    shape[len - d].0 -= 12;
+                }
+                match self.voicing {
+                    Voicing::Open => shape
+                        .iter_mut()
+                        .skip(1)
+                        .step_by(2)
+                        .for_each(|(offset, _)| *offset += 12),
+                    Voicing::Spread => shape[0].0 -= 12,
+                    _ => {}
+                }
+                shape
+            })
+            .collect()
+    }
+}
+
+/// Stacks tones in given order, each of which is the nearest above the previous.
+fn close(order: &[(usize, Tone)]) -> Vec<(i32, Tone)> {
+    let mut shape: Vec<(i32, Tone)> = vec![];
+    for (interval, tone) in order {
+        let class = (*interval % 12) as i32;
+        let offset = match shape.last() {
+            None => class,
+            Some((previous, _)) => {
+                let mut offset = previous - previous.rem_euclid(12) + class;
+                while offset <= *previous {
+                    offset += 12;
+                }
+                offset
+            }
+        };
+        shape.push((offset, *tone));
+    }
+    shape
+}
+
+/// Returns a chord of `pitches` in ascending order, whose root is the lowest `root`.
+/// - If `slash` is `true`, the lowest pitch is taken as the bass of a slash chord.
+fn into_chord(mut pitches: Vec<Pitch<Tone>>, root: &Tone, slash: bool) -> CoreChord<Pitch<Tone>> {
+    let bass = slash.then(|| pitches.remove(0));
+    let index = pitches
+        .iter()
+        .position(|p| class(p.class()) == class(root))
+        .unwrap_or(0);
+    let mut others = pitches.split_off(index);
+    let root = others.remove(0);
+    let inversion = if pitches.is_empty() {
+        0
+    } else {
+        others.len() + 1
+    };
+    others.append(&mut pitches);
+    let chord = CoreChord::new(root, others).with_inversion(inversion);
+    match bass {
+        Some(bass) => chord.with_bass(bass),
+        None => chord,
+    }
+}
+
+pub(crate) fn class(tone: &Tone) -> usize {
+    usize::from(*tone) % 12
+}
+
+/// Returns semitones from C0 to `pitch`, where a written "Cb4" lies below "C4" and "B#3"
+/// is "C4".
+/// - A pitch below C0 is taken as C0.
+pub(crate) fn number(pitch: &Pitch<Tone>) -> usize {
+    let tone = pitch.class();
+    let semitones = *tone.tone() as i32 + *tone.accidental() as i32;
+    (pitch.oct() as i32 * 12 + semitones).max(0) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn pitches(pitches: &[&str]) -> Vec<Pitch<Tone>> {
+        pitches
+            .iter()
+            .map(|p| {
+                let (tone, oct) = p.split_at(p.len() - 1);
+                Pitch::new(tone.parse().unwrap(), oct.parse().unwrap())
+            })
+            .collect()
+    }
+
+    fn range(low: &str, high: &str) -> PitchRange<Tone> {
+        let p = pitches(&[low, high]);
+        PitchRange::new(p[0], p[1])
+    }
+
+    fn first(voicer: Voicer, chord: &str, low: &str, high: &str) -> Vec<Pitch<Tone>> {
+        let chord: Chord<Tone> = chord.parse().unwrap();
+        let voicings = voicer.voice(&chord, &range(low, high));
+        voicings[0].clone().into_voiced_vec()
+    }
+
+    #[test]
+    fn close() {
+        let voicer = Voicer::new(Voicing::Close);
+        let voicing = first(voicer, "Cmaj7", "C4", "C5");
+        assert_eq!(voicing, pitches(&["C4", "E4", "G4", "B4"]));
+        let voicing = first(voicer.with_bottom("E".parse().unwrap()), "C", "C4", "C6");
+        assert_eq!(voicing, pitches(&["E4", "G4", "C5"]));
+        let voicing = first(voicer.with_voices(4), "C", "C4", "C6");
+        assert_eq!(voicing, pitches(&["C4", "E4", "G4", "C5"]));
+        let voicing = first(voicer.with_voices(4), "G13", "C4", "C6");
+        assert_eq!(voicing, pitches(&["E4", "G4", "B4", "F5"]));
+        let voicing = first(voicer.with_voices(2), "C", "C4", "C6");
+        assert_eq!(voicing, pitches(&["C4", "E4"]));
+        let voicing = first(voicer.with_voices(1), "C", "C4", "C6");
+        assert_eq!(voicing, pitches(&["C4"]));
+    }
+
+    #[test]
+    fn drop() {
+        let voicer = Voicer::new(Voicing::Drop2);
+        let voicing = first(voicer, "Cmaj7", "C3", "C5");
+        assert_eq!(voicing, pitches(&["C3", "G3", "B3", "E4"]));
+        let voicing = first(voicer.with_top("B".parse().unwrap()), "Cmaj7", "C3", "C5");
+        assert_eq!(voicing, pitches(&["G3", "C4", "E4", "B4"]));
+        let voicing = first(Voicer::new(Voicing::Drop3), "C7", "C3", "C5");
+        assert_eq!(voicing, pitches(&["C3", "As3", "E4", "G4"]));
+        let voicing = first(Voicer::new(Voicing::Drop24), "C7", "C3", "C5");
+        assert_eq!(voicing, pitches(&["C3", "G3", "E4", "As4"]));
+    }
+
+    #[test]
+    fn shell_open_spread() {
+        let voicing = first(Voicer::new(Voicing::Shell), "Dm9", "C3", "C5");
+        assert_eq!(voicing, pitches(&["D3", "F3", "C4"]));
+        let voicing = first(Voicer::new(Voicing::Open), "C", "C3", "C5");
+        assert_eq!(voicing, pitches(&["C3", "G3", "E4"]));
+        let voicing = first(Voicer::new(Voicing::Spread), "C", "C3", "C5");
+        assert_eq!(voicing, pitches(&["C3", "E4", "G4"]));
+    }
+
+    #[test]
+    fn slash_and_range() {
+        let chord: Chord<Tone> = "C/D".parse().unwrap();
+        let voicings = Voicer::new(Voicing::Close).voice(&chord, &range("C3", "C4"));
+        assert_eq!(voicings.len(), 1);
+        assert_eq!(voicings[0].bass(), Some(&pitches(&["D3"])[0]));
+        assert_eq!(
+            voicings[0].clone().into_voiced_vec(),
+            pitches(&["D3", "E3", "G3", "C4"])
+        );
+        let chord: Chord<Tone> = "C".parse().unwrap();
+        let voicings = Voicer::new(Voicing::Close).voice(&chord, &range("C4", "D4"));
+        assert!(voicings.is_empty());
+    }
+}
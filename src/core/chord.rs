@@ -1,34 +1,179 @@
 use std::fmt::Debug;
 
-use super::{Degree, Distance, Keyboard, Octave, Pitch, Scale, Scaled};
+use super::{AlteredDegree, Degree, Distance, Keyboard, Octave, Pitch, Scale, Scaled};
 
+/// A chord of tones on a `Keyboard`, which are a root, the other tones, and an inversion
+/// or a slash bass.
+/// - A named chord is `chord::Chord`, which converts to and from this chord.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Chord<T> {
     root: T,
     other: Vec<T>,
+    inversion: usize,
+    bass: Option<T>,
 }
 
 impl<T> Chord<T> {
     pub fn new(root: T, other: Vec<T>) -> Self {
-        Self { root, other }
+        Self {
+            root,
+            other,
+            inversion: 0,
+            bass: None,
+        }
+    }
+
+    /// Returns an inverted chord whose lowest tone is the `inversion`-th tone from the root,
+    /// like 1 for the first inversion.
+    /// - `inversion` wraps around the number of tones.
+    pub fn with_inversion(self, inversion: usize) -> Self {
+        let inversion = inversion % (self.other.len() + 1);
+        Self { inversion, ..self }
+    }
+
+    /// Returns a slash chord whose bass is given `bass`, like "C/D".
+    pub fn with_bass(self, bass: T) -> Self {
+        Self {
+            bass: Some(bass),
+            ..self
+        }
+    }
+
+    pub fn root(&self) -> &T {
+        &self.root
+    }
+
+    pub fn others(&self) -> &[T] {
+        &self.other
     }
 
+    /// Returns an index of the lowest tone from the root, which is 0 in the root position.
+    pub fn inversion(&self) -> usize {
+        self.inversion
+    }
+
+    /// Returns the bass of a slash chord, or `None` if the bass is not specified.
+    pub fn bass(&self) -> Option<&T> {
+        self.bass.as_ref()
+    }
+
+    /// Returns the lowest tone, which is the bass of a slash chord or the inverted tone.
+    pub fn lowest(&self) -> &T {
+        match (&self.bass, self.inversion) {
+            (Some(bass), _) => bass,
+            (None, 0) => &self.root,
+            (None, i) => &self.other[i - 1],
+        }
+    }
+
+    /// Returns a figured bass label of an inverted triad or seventh chord.
+    /// - Returns `None` for a slash chord, or a chord with other than 3 or 4 tones.
+    pub fn figure(&self) -> Option<Figure> {
+        if self.bass.is_some() {
+            return None;
+        }
+        match (self.other.len(), self.inversion) {
+            (2, 0) => Some(Figure::FiveThree),
+            (2, 1) => Some(Figure::Six),
+            (2, 2) => Some(Figure::SixFour),
+            (3, 0) => Some(Figure::Seven),
+            (3, 1) => Some(Figure::SixFive),
+            (3, 2) => Some(Figure::FourThree),
+            (3, 3) => Some(Figure::FourTwo),
+            _ => None,
+        }
+    }
+
+    /// Returns tones from the root, regardless of the inversion or the bass.
     pub fn into_vec(self) -> Vec<T> {
         let mut vec = self.other;
         vec.insert(0, self.root);
         vec
     }
+
+    /// Returns tones from the lowest, which starts with the bass of a slash chord
+    /// and follows the inversion.
+    pub fn into_voiced_vec(self) -> Vec<T> {
+        let inversion = self.inversion;
+        let bass = self.bass;
+        let mut vec = self.other;
+        vec.insert(0, self.root);
+        vec.rotate_left(inversion);
+        if let Some(bass) = bass {
+            vec.insert(0, bass);
+        }
+        vec
+    }
+}
+
+/// Figured bass labels of inverted triads and seventh chords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Figure {
+    FiveThree,
+    Six,
+    SixFour,
+    Seven,
+    SixFive,
+    FourThree,
+    FourTwo,
+}
+
+impl Figure {
+    /// Returns a label like "6/4".
+    pub fn label(&self) -> &'static str {
+        match self {
+            Figure::FiveThree => "5/3",
+            Figure::Six => "6",
+            Figure::SixFour => "6/4",
+            Figure::Seven => "7",
+            Figure::SixFive => "6/5",
+            Figure::FourThree => "4/3",
+            Figure::FourTwo => "4/2",
+        }
+    }
 }
 
 impl Chord<Degree> {
-    pub fn to_pitch<'a, O: Octave>(&'a self, key: &'a Keyboard<O>) -> Chord<Pitch<&O::PitchClass>> {
+    pub fn to_pitch<'a, O: Octave>(
+        &'a self,
+        key: &'a Keyboard<O>,
+    ) -> Chord<Pitch<&'a O::PitchClass>> {
         let root = key.get_pitch(&self.root);
-        let other = self
-            .other
-            .iter()
-            .map(|&x| key.get_pitch(&(self.root.value() + x.value() - 2)))
-            .collect();
-        Chord { root, other }
+        let pitch = |x: &Degree| key.get_pitch(&(self.root.value() + x.value() - 2));
+        Chord {
+            root,
+            other: self.other.iter().map(pitch).collect(),
+            inversion: self.inversion,
+            bass: self.bass.as_ref().map(pitch),
+        }
+    }
+}
+
+impl Chord<AlteredDegree> {
+    /// Returns a `Chord` of pitches that given altered degrees point on `key`.
+    /// - The root is a degree of `key`, and the others are degrees from the root.
+    /// - Returns `None` if any pitch falls below the first pitch of `key`.
+    pub fn to_pitch<'a, S: Scale, O: Octave>(
+        &'a self,
+        key: &'a Keyboard<Scaled<S, O>>,
+    ) -> Option<Chord<Pitch<&'a O::PitchClass>>> {
+        let root = key.get_altered_pitch(&self.root)?;
+        let pitch = |x: &AlteredDegree| {
+            let degree = Degree::new(self.root.degree().value() + x.degree().value() - 1);
+            let altered = AlteredDegree::new(degree.unwrap(), x.alteration());
+            key.get_altered_pitch(&altered)
+        };
+        let other = self.other.iter().map(pitch).collect::<Option<Vec<_>>>()?;
+        let bass = match &self.bass {
+            Some(bass) => Some(pitch(bass)?),
+            None => None,
+        };
+        Some(Chord {
+            root,
+            other,
+            inversion: self.inversion,
+            bass,
+        })
     }
 }
 
@@ -38,16 +183,78 @@ impl<T: Clone> Chord<Pitch<T>> {
         keyboard: &'a Keyboard<Scaled<impl Scale, O>>,
     ) -> Option<Chord<Degree>> {
         let key = keyboard.get_pitch(&0_usize).deref();
-        let Some(root_deg) = keyboard.measure(&key, &self.root) else {
-            return None;
-        };
+        let root_deg = keyboard.measure(&key, &self.root)?;
         let others = self.other.iter().map(|p| keyboard.measure(&self.root, p));
         if others.clone().any(|o| o.is_none()) {
             return None;
         }
+        let bass = match &self.bass {
+            Some(bass) => Some(keyboard.measure(&self.root, bass)?),
+            None => None,
+        };
         Some(Chord {
             root: root_deg,
             other: others.map(|o| o.unwrap()).collect(),
+            inversion: self.inversion,
+            bass,
+        })
+    }
+
+    /// Returns a chord whose lowest pitch is moved up above the highest by periods,
+    /// which is the next inversion.
+    /// - Returns `None` if any pitch is not found on `keyboard`.
+    pub fn invert_up<O: Octave<PitchClass = T>>(&self, keyboard: &Keyboard<O>) -> Option<Self> {
+        let mut pitches = self.clone().into_vec();
+        let numbers = pitches
+            .iter()
+            .map(|p| keyboard.as_number(p))
+            .collect::<Option<Vec<_>>>()?;
+        let (lowest, _) = numbers.iter().enumerate().min_by_key(|(_, n)| **n)?;
+        let highest = *numbers.iter().max()?;
+        let mut pitch = pitches[lowest].clone();
+        while keyboard.as_number(&pitch)? <= highest {
+            pitch = Pitch::new(pitch.class().clone(), pitch.oct() + 1);
+        }
+        pitches[lowest] = pitch;
+        self.with_pitches(pitches, keyboard)
+    }
+
+    /// Returns a chord whose highest pitch is moved down below the lowest by periods,
+    /// which is the previous inversion.
+    /// - Returns `None` if any pitch is not found on `keyboard`, or falls below the period 0.
+    pub fn invert_down<O: Octave<PitchClass = T>>(&self, keyboard: &Keyboard<O>) -> Option<Self> {
+        let mut pitches = self.clone().into_vec();
+        let numbers = pitches
+            .iter()
+            .map(|p| keyboard.as_number(p))
+            .collect::<Option<Vec<_>>>()?;
+        let (highest, _) = numbers.iter().enumerate().max_by_key(|(_, n)| **n)?;
+        let lowest = *numbers.iter().min()?;
+        let mut pitch = pitches[highest].clone();
+        while keyboard.as_number(&pitch)? >= lowest {
+            pitch = Pitch::new(pitch.class().clone(), pitch.oct().checked_sub(1)?);
+        }
+        pitches[highest] = pitch;
+        self.with_pitches(pitches, keyboard)
+    }
+
+    /// Returns a chord of given `pitches` from the root, whose inversion follows the lowest.
+    fn with_pitches<O: Octave<PitchClass = T>>(
+        &self,
+        mut pitches: Vec<Pitch<T>>,
+        keyboard: &Keyboard<O>,
+    ) -> Option<Self> {
+        let numbers = pitches
+            .iter()
+            .map(|p| keyboard.as_number(p))
+            .collect::<Option<Vec<_>>>()?;
+        let (inversion, _) = numbers.iter().enumerate().min_by_key(|(_, n)| **n)?;
+        let root = pitches.remove(0);
+        Some(Chord {
+            root,
+            other: pitches,
+            inversion,
+            bass: self.bass.clone(),
         })
     }
 }
@@ -55,42 +262,97 @@ impl<T: Clone> Chord<Pitch<T>> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        prelude::{Degree, Keyboard, Pitch, Scaled, Tone, Twelve},
+        prelude::{AlteredDegree, Degree, Keyboard, Pitch, Scaled, Tone, Twelve},
         scale::Diatonic,
     };
 
-    use super::Chord;
+    use super::{Chord, Figure};
 
     #[test]
     fn to_pitch() {
         let scale = Diatonic::minor(&"A".parse().unwrap());
         let scaled = Scaled::new(scale, Twelve);
         let keyboard = Keyboard::new(scaled);
-        let chord = Chord::<Degree> {
-            root: Degree::new(1).unwrap(),
-            other: vec![Degree::new(3).unwrap(), Degree::new(5).unwrap()],
-        };
+        let chord = Chord::<Degree>::new(
+            Degree::new(1).unwrap(),
+            vec![Degree::new(3).unwrap(), Degree::new(5).unwrap()],
+        );
         let c = chord.to_pitch(&keyboard);
         assert_eq!(c.root, Pitch::new(&"A".parse().unwrap(), 0));
-        assert_eq!(c.other.get(0), Some(&Pitch::new(&"C".parse().unwrap(), 0)));
+        assert_eq!(c.other.first(), Some(&Pitch::new(&"C".parse().unwrap(), 0)));
         assert_eq!(c.other.get(1), Some(&Pitch::new(&"E".parse().unwrap(), 0)));
     }
 
+    #[test]
+    fn altered_to_pitch() {
+        let scale = Diatonic::major(&"C".parse().unwrap());
+        let keyboard = Keyboard::new(Scaled::new(scale, Twelve));
+        let chord = Chord::<AlteredDegree>::new(
+            "1".parse().unwrap(),
+            vec![
+                "b3".parse().unwrap(),
+                "5".parse().unwrap(),
+                "b7".parse().unwrap(),
+            ],
+        );
+        let c = chord.to_pitch(&keyboard).unwrap();
+        assert_eq!(c.root, Pitch::new(&"C".parse().unwrap(), 0));
+        assert_eq!(
+            c.other.first(),
+            Some(&Pitch::new(&"Ds".parse().unwrap(), 0))
+        );
+        assert_eq!(c.other.get(1), Some(&Pitch::new(&"G".parse().unwrap(), 0)));
+        assert_eq!(c.other.get(2), Some(&Pitch::new(&"As".parse().unwrap(), 0)));
+    }
+
     #[test]
     fn to_degree() {
         let scale = Diatonic::minor(&"A".parse().unwrap());
         let scaled = Scaled::new(scale, Twelve);
         let keyboard = Keyboard::new(scaled);
-        let chord = Chord::<Pitch<Tone>> {
-            root: Pitch::new("A".parse().unwrap(), 0),
-            other: vec![
+        let chord = Chord::<Pitch<Tone>>::new(
+            Pitch::new("A".parse().unwrap(), 0),
+            vec![
                 Pitch::new("C".parse().unwrap(), 1),
                 Pitch::new("E".parse().unwrap(), 1),
             ],
-        };
+        );
         let c = chord.to_degree(&keyboard).unwrap();
         assert_eq!(c.root, Degree::new(1).unwrap());
-        assert_eq!(c.other.get(0), Some(&Degree::new(3).unwrap()));
+        assert_eq!(c.other.first(), Some(&Degree::new(3).unwrap()));
         assert_eq!(c.other.get(1), Some(&Degree::new(5).unwrap()));
     }
+
+    #[test]
+    fn figure() {
+        let chord = Chord::new("C", vec!["E", "G", "B"]);
+        assert_eq!(chord.figure(), Some(Figure::Seven));
+        let chord = chord.with_inversion(2);
+        assert_eq!(chord.figure().map(|f| f.label()), Some("4/3"));
+        assert_eq!(chord.lowest(), &"G");
+        assert_eq!(chord.clone().into_vec(), vec!["C", "E", "G", "B"]);
+        assert_eq!(chord.into_voiced_vec(), vec!["G", "B", "C", "E"]);
+        let chord = Chord::new("C", vec!["E", "G"]).with_bass("D");
+        assert_eq!(chord.figure(), None);
+        assert_eq!(chord.into_voiced_vec(), vec!["D", "C", "E", "G"]);
+    }
+
+    #[test]
+    fn invert() {
+        let keyboard = Keyboard::new(Twelve);
+        let pitch = |t: &str, o| Pitch::new(t.parse::<Tone>().unwrap(), o);
+        let chord = Chord::new(pitch("C", 4), vec![pitch("E", 4), pitch("G", 4)]);
+        let first = chord.invert_up(&keyboard).unwrap();
+        assert_eq!(first.inversion(), 1);
+        assert_eq!(first.root(), &pitch("C", 5));
+        let second = first.invert_up(&keyboard).unwrap();
+        assert_eq!(second.figure(), Some(Figure::SixFour));
+        assert_eq!(second.lowest(), &pitch("G", 4));
+        let back = second.invert_down(&keyboard).unwrap();
+        assert_eq!(back, first);
+        let root = back.invert_down(&keyboard).unwrap();
+        assert_eq!(root, chord);
+        let low = Chord::new(pitch("C", 0), vec![pitch("E", 0), pitch("G", 0)]);
+        assert_eq!(low.invert_down(&keyboard), None);
+    }
 }
@@ -10,14 +10,14 @@ impl Interval {
         let a: usize = a.into();
         let b: usize = b.into();
         if a > b {
-            Self(-1 * (a - b) as i32)
+            Self(-((a - b) as i32))
         } else {
             Self((b - a) as i32)
         }
     }
 
     pub fn value(&self) -> usize {
-        self.0.abs() as usize
+        self.0.unsigned_abs() as usize
     }
 
     pub fn direction(&self) -> i32 {
@@ -64,6 +64,39 @@ impl Number for Degree {
     }
 }
 
+impl From<Degree> for AlteredDegree {
+    fn from(value: Degree) -> Self {
+        AlteredDegree::natural(value)
+    }
+}
+
+/// A `Degree` that is chromatically altered, like "b3" or "#11".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlteredDegree {
+    degree: Degree,
+    alteration: i32,
+}
+
+impl AlteredDegree {
+    /// Returns an `AlteredDegree` instance.
+    /// - `alteration` is a number of chromatic steps, negative for flats and positive for sharps.
+    pub fn new(degree: Degree, alteration: i32) -> Self {
+        Self { degree, alteration }
+    }
+
+    pub fn natural(degree: Degree) -> Self {
+        Self::new(degree, 0)
+    }
+
+    pub fn degree(&self) -> Degree {
+        self.degree
+    }
+
+    pub fn alteration(&self) -> i32 {
+        self.alteration
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct DegreeConstructError;
 
@@ -1,6 +1,9 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, error::Error, fmt::Display};
 
-use super::{distance::Degree, pitch::Pitch};
+use super::{
+    distance::{AlteredDegree, Degree},
+    pitch::Pitch,
+};
 
 pub use super::number::*;
 
@@ -10,12 +13,39 @@ pub trait Octave: Sized {
     /// Returns a reference of `PitchClass` on given `number`.
     fn get_class(&self, number: &Cycle) -> &Self::PitchClass;
 
+    /// Returns a reference of `PitchClass` on given `number`.
+    /// - Returns an error if this `Octave` is empty or lacks the class.
+    fn try_get_class(&self, number: &Cycle) -> Result<&Self::PitchClass, LookupError> {
+        number.try_value().ok_or(LookupError::Empty)?;
+        Ok(self.get_class(number))
+    }
+
     /// Returns a `number` as `usize` of given `class`.
     /// - Returns `None` if given `class` is not found on this `Octave`.
     fn get_number(&self, class: &Self::PitchClass) -> Option<usize>;
 
     fn len(&self) -> usize;
 
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a frequency ratio that one period of this `Octave` spans.
+    /// - Defaults to `2.0`, an octave.
+    /// - Returns `None` if this `Octave` does not repeat.
+    fn period(&self) -> Option<f64> {
+        Some(2.0)
+    }
+
+    /// Returns a frequency ratio of given `class` from the first class of a period.
+    /// - Defaults to an equal division of the period.
+    /// - Returns `None` if given `class` is not found on this `Octave`.
+    fn ratio(&self, class: &Self::PitchClass) -> Option<f64> {
+        let period = self.period()?;
+        let number = self.get_number(class)?;
+        Some(period.powf(number as f64 / self.len() as f64))
+    }
+
     fn iter(&self) -> ClassIter<'_, Self> {
         ClassIter {
             inner: self,
@@ -38,7 +68,7 @@ impl<'a, Oct: Octave> Iterator for ClassIter<'a, Oct> {
         }
         let class = self.inner.get_class(&self.cycle);
         self.cycle.increment(1);
-        return Some(class);
+        Some(class)
     }
 }
 
@@ -49,6 +79,11 @@ impl<V: PartialEq + PartialOrd> Octave for HashMap<usize, V> {
         self.get(&number.value()).unwrap()
     }
 
+    fn try_get_class(&self, number: &Cycle) -> Result<&Self::PitchClass, LookupError> {
+        let value = number.try_value().ok_or(LookupError::Empty)?;
+        self.get(&value).ok_or(LookupError::OutOfRange(value))
+    }
+
     fn len(&self) -> usize {
         self.len()
     }
@@ -65,6 +100,11 @@ impl<V: PartialEq + PartialOrd> Octave for Vec<V> {
         self.get(number.value()).unwrap()
     }
 
+    fn try_get_class(&self, number: &Cycle) -> Result<&Self::PitchClass, LookupError> {
+        let value = number.try_value().ok_or(LookupError::Empty)?;
+        self.get(value).ok_or(LookupError::OutOfRange(value))
+    }
+
     fn len(&self) -> usize {
         self.len()
     }
@@ -74,41 +114,167 @@ impl<V: PartialEq + PartialOrd> Octave for Vec<V> {
     }
 }
 
+#[derive(Debug)]
 pub struct Keyboard<T>(T);
 
 impl<T> Keyboard<T> {
     pub fn new(oct: T) -> Self {
         Self(oct)
     }
+
+    /// Returns a `Keyboard` instance.
+    ///
+    /// # Errors
+    /// - if `oct` is empty, or lacks a class on any number within its length.
+    pub fn try_new(oct: T) -> Result<Self, LookupError>
+    where
+        T: Octave,
+    {
+        if oct.is_empty() {
+            return Err(LookupError::Empty);
+        }
+        for number in 0..oct.len() {
+            oct.try_get_class(&Cycle::new(number, oct.len()))?;
+        }
+        Ok(Self(oct))
+    }
+
+    pub fn octave(&self) -> &T {
+        &self.0
+    }
 }
 
 impl<Oct: Octave> Keyboard<Oct> {
+    /// Returns a class on given `number`.
+    /// # Panics
+    /// - if `number` lies beyond the last class of an `Octave` that does not repeat.
+    ///   Use `try_get_class` for such an `Octave`.
     pub fn get_class(&self, number: &impl Number) -> &Oct::PitchClass {
+        assert!(
+            self.repeats_to(number.value()),
+            "number {} is beyond the last class",
+            number.value()
+        );
         let cycle = Cycle::new(number.to_owned(), self.0.len());
         self.0.get_class(&cycle)
     }
 
+    /// Returns a pitch on given `number`.
+    /// # Panics
+    /// - if `number` lies beyond the last class of an `Octave` that does not repeat.
+    ///   Use `try_get_pitch` for such an `Octave`.
     pub fn get_pitch(&self, number: &impl Number) -> Pitch<&Oct::PitchClass> {
         let class = self.get_class(number);
         let oct = number.value() / self.0.len();
         Pitch::new(class, oct)
     }
 
+    /// Returns a class on given `number`.
+    /// - Returns an error if this `Octave` is empty or lacks the class, or if `number` lies
+    ///   beyond the last class of an `Octave` that does not repeat.
+    pub fn try_get_class(&self, number: &impl Number) -> Result<&Oct::PitchClass, LookupError> {
+        if !self.repeats_to(number.value()) {
+            return Err(LookupError::OutOfRange(number.value()));
+        }
+        let cycle = Cycle::new(number.to_owned(), self.0.len());
+        self.0.try_get_class(&cycle)
+    }
+
+    pub fn try_get_pitch(
+        &self,
+        number: &impl Number,
+    ) -> Result<Pitch<&Oct::PitchClass>, LookupError> {
+        let class = self.try_get_class(number)?;
+        let oct = number.value() / self.0.len();
+        Ok(Pitch::new(class, oct))
+    }
+
+    /// Returns a number of given `pitch`.
+    /// - Returns `None` if the class is not found, or if the pitch lies below the number 0
+    ///   or beyond the last class of an `Octave` that does not repeat.
     pub fn as_number(&self, pitch: &Pitch<Oct::PitchClass>) -> Option<usize> {
         let start = self.get_class(&0_usize);
         let oct = if pitch.class() < start {
-            pitch.oct() - 1
+            pitch.oct().checked_sub(1)?
         } else {
             pitch.oct()
         };
         self.0
             .get_number(pitch.class())
             .map(|x| x + self.0.len() * oct)
+            .filter(|number| self.repeats_to(*number))
+    }
+
+    /// Returns `true` if this `Octave` repeats, or `number` is within its classes.
+    fn repeats_to(&self, number: usize) -> bool {
+        self.0.period().is_some() || number < self.0.len()
     }
 
     pub fn class_iter(&self) -> ClassIter<'_, Oct> {
         self.0.iter()
     }
+
+    /// Returns a frequency ratio from the number 0 to given `number`.
+    /// - Returns `None` if this `Octave` is empty or does not repeat, or lacks a ratio of
+    ///   a class.
+    pub fn ratio(&self, number: &impl Number) -> Option<f64> {
+        let len = self.0.len();
+        let period = self.0.period()?;
+        let value = number.value();
+        let ratio = self.ratio_from_first(self.get_class(&value.checked_rem(len)?))?;
+        Some(ratio * period.powi((value / len) as i32))
+    }
+
+    /// Returns a frequency ratio of given `class` from the class on the number 0 within
+    /// a period.
+    fn ratio_from_first(&self, class: &Oct::PitchClass) -> Option<f64> {
+        let first = self.0.ratio(self.get_class(&0_usize))?;
+        let ratio = self.0.ratio(class)? / first;
+        // The number 0 of a scaled keyboard may lie above the first class of the period.
+        match ratio < 1.0 {
+            true => Some(ratio * self.0.period()?),
+            false => Some(ratio),
+        }
+    }
+
+    /// Returns a number from `from` upward whose frequency ratio from `from` is the nearest
+    /// to given `ratio`, like 6 steps of 19-EDO for a major third.
+    /// - Returns `None` if this `Octave` does not repeat, or lacks a ratio of a class.
+    pub fn nearest(&self, from: usize, ratio: f64) -> Option<usize> {
+        let target = (self.ratio(&from)? * ratio).ln();
+        let mut number = from;
+        let mut distance = (self.ratio(&number)?.ln() - target).abs();
+        loop {
+            let next = (self.ratio(&(number + 1))?.ln() - target).abs();
+            if next >= distance {
+                return Some(number);
+            }
+            number += 1;
+            distance = next;
+        }
+    }
+
+    /// Returns a frequency of given `pitch`.
+    /// - `reference` is a frequency of the class on the number 0, which is the first class
+    ///   of a scale on a scaled keyboard.
+    /// - Returns `None` if the class is not found, or if the pitch lies beyond the first
+    ///   period of a non-repeating `Octave`.
+    pub fn frequency(&self, pitch: &Pitch<Oct::PitchClass>, reference: f64) -> Option<f64> {
+        let ratio = self.ratio_from_first(pitch.class())?;
+        let period = match (self.0.period(), pitch.period()) {
+            (_, 0) => 1.0,
+            (Some(period), n) => period.powi(n as i32),
+            (None, _) => return None,
+        };
+        Some(reference * period * ratio)
+    }
+}
+
+/// A melodic direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Ascending,
+    Descending,
 }
 
 pub trait Scale {
@@ -116,11 +282,44 @@ pub trait Scale {
     fn convert(&self, number: impl Number) -> usize;
 
     fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a `number` as `usize` that mapped from given `number`.
+    /// - Returns an error if `number` is out of this scale.
+    fn try_convert(&self, number: impl Number) -> Result<usize, LookupError> {
+        match number.value() {
+            value if value < self.len() => Ok(self.convert(number)),
+            value => Err(LookupError::OutOfRange(value)),
+        }
+    }
+
+    /// Returns a `number` as `usize` that mapped from given `number` on the form used
+    /// when moving toward `direction`.
+    /// - Defaults to `convert`, for a scale whose form is same in both directions.
+    fn convert_toward(&self, number: impl Number, _direction: Direction) -> usize {
+        self.convert(number)
+    }
+
+    /// Returns a length of the form used when moving toward `direction`.
+    /// - Defaults to `len`.
+    fn len_toward(&self, _direction: Direction) -> usize {
+        self.len()
+    }
 }
 
 impl Scale for Vec<usize> {
     fn convert(&self, number: impl Number) -> usize {
-        self.get(number.value()).unwrap().clone()
+        *self.get(number.value()).unwrap()
+    }
+
+    fn try_convert(&self, number: impl Number) -> Result<usize, LookupError> {
+        let value = number.value();
+        self.get(value)
+            .copied()
+            .ok_or(LookupError::OutOfRange(value))
     }
 
     fn len(&self) -> usize {
@@ -128,6 +327,7 @@ impl Scale for Vec<usize> {
     }
 }
 
+#[derive(Debug)]
 pub struct Scaled<S, O> {
     oct: O,
     scaler: S,
@@ -137,6 +337,46 @@ impl<S, O> Scaled<S, O> {
     pub fn new(scale: S, oct: O) -> Self {
         Self { oct, scaler: scale }
     }
+
+    /// Returns a `Scaled` instance.
+    ///
+    /// # Errors
+    /// - if `scale` or `oct` is empty.
+    /// - if `scale` is not strictly increasing, or spans more than an `oct`.
+    /// - if `scale` points on a class that `oct` lacks.
+    pub fn try_new(scale: S, oct: O) -> Result<Self, ScaleError>
+    where
+        S: Scale,
+        O: Octave,
+    {
+        if oct.is_empty() {
+            return Err(ScaleError::EmptyOctave);
+        }
+        if scale.is_empty() {
+            return Err(ScaleError::EmptyScale);
+        }
+        let numbers = (0..scale.len())
+            .map(|i| scale.try_convert(i))
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(i) = numbers.windows(2).position(|w| w[0] >= w[1]) {
+            return Err(ScaleError::NotIncreasing(i + 1));
+        }
+        if let Some(i) = numbers.iter().position(|n| n - numbers[0] >= oct.len()) {
+            return Err(ScaleError::OutOfOctave(i));
+        }
+        for number in numbers {
+            oct.try_get_class(&Cycle::new(number, oct.len()))?;
+        }
+        Ok(Self::new(scale, oct))
+    }
+
+    pub fn scale(&self) -> &S {
+        &self.scaler
+    }
+
+    pub fn octave(&self) -> &O {
+        &self.oct
+    }
 }
 
 impl<S: Scale, O: Octave> Octave for Scaled<S, O> {
@@ -147,10 +387,18 @@ impl<S: Scale, O: Octave> Octave for Scaled<S, O> {
         self.oct.get_class(&cycle)
     }
 
-    fn get_number(&self, class: &Self::PitchClass) -> Option<usize> {
-        if self.oct.get_number(class).is_none() {
-            return None;
+    fn try_get_class(&self, number: &Cycle) -> Result<&Self::PitchClass, LookupError> {
+        let value = number.try_value().ok_or(LookupError::Empty)?;
+        let converted = self.scaler.try_convert(value)?;
+        if self.oct.period().is_none() && converted >= self.oct.len() {
+            return Err(LookupError::OutOfRange(converted));
         }
+        self.oct
+            .try_get_class(&Cycle::new(converted, self.oct.len()))
+    }
+
+    fn get_number(&self, class: &Self::PitchClass) -> Option<usize> {
+        self.oct.get_number(class)?;
         let mut c = Cycle::new(0_usize, self.len());
         loop {
             let current_class = self.get_class(&c);
@@ -167,6 +415,98 @@ impl<S: Scale, O: Octave> Octave for Scaled<S, O> {
     fn len(&self) -> usize {
         self.scaler.len()
     }
+
+    fn period(&self) -> Option<f64> {
+        self.oct.period()
+    }
+
+    fn ratio(&self, class: &Self::PitchClass) -> Option<f64> {
+        self.oct.ratio(class)
+    }
+}
+
+impl<S: Scale, O: Octave> Keyboard<Scaled<S, O>> {
+    /// Returns a `Pitch` of given altered `degree` of this scale.
+    /// - The alteration is applied on the underlying `Octave`, so "b3" of a major scale is
+    ///   one step below its 3rd.
+    /// - Returns `None` if the pitch falls below the first pitch of this keyboard, or beyond
+    ///   the last class of an `Octave` that does not repeat.
+    pub fn get_altered_pitch(&self, degree: &AlteredDegree) -> Option<Pitch<&O::PitchClass>> {
+        let scaled = &self.0;
+        let number = Number::value(&degree.degree());
+        let oct_len = scaled.oct.len() as i64;
+        let period = (number / scaled.len()) as i64;
+        let base = scaled.scaler.convert(0_usize) as i64;
+        let altered = scaled.scaler.convert(number % scaled.len()) as i64
+            + period * oct_len
+            + degree.alteration() as i64;
+        if altered < base || scaled.oct.period().is_none() && altered >= oct_len {
+            return None;
+        }
+        let class = scaled
+            .oct
+            .get_class(&Cycle::new(altered as usize, scaled.oct.len()));
+        Some(Pitch::new(class, ((altered - base) / oct_len) as usize))
+    }
+}
+
+impl<S: Scale, O: Octave> Keyboard<Scaled<S, O>> {
+    /// Returns an iterator that walks up this scale from given `number`,
+    /// using the ascending form of the scale.
+    pub fn ascend(&self, number: usize) -> Walk<'_, S, O> {
+        Walk {
+            inner: &self.0,
+            direction: Direction::Ascending,
+            next: Some(number),
+        }
+    }
+
+    /// Returns an iterator that walks down this scale from given `number` to 0,
+    /// using the descending form of the scale.
+    /// - `number` counts the steps of the descending form.
+    pub fn descend(&self, number: usize) -> Walk<'_, S, O> {
+        Walk {
+            inner: &self.0,
+            direction: Direction::Descending,
+            next: Some(number),
+        }
+    }
+}
+
+/// An iterator that walks on a scale toward a `Direction`.
+pub struct Walk<'a, S, O> {
+    inner: &'a Scaled<S, O>,
+    direction: Direction,
+    next: Option<usize>,
+}
+
+impl<'a, S: Scale, O: Octave> Iterator for Walk<'a, S, O> {
+    type Item = Pitch<&'a O::PitchClass>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let number = self.next?;
+        self.next = match self.direction {
+            Direction::Ascending => Some(number + 1),
+            Direction::Descending => number.checked_sub(1),
+        };
+        let scaler = &self.inner.scaler;
+        let len = scaler.len_toward(self.direction);
+        let oct_len = self.inner.oct.len();
+        let base = scaler.convert_toward(0_usize, self.direction);
+        let converted =
+            scaler.convert_toward(number % len, self.direction) + number / len * oct_len;
+        if self.inner.oct.period().is_none() && converted >= oct_len {
+            self.next = None;
+            return None;
+        }
+        let class = self.inner.oct.get_class(&Cycle::new(converted, oct_len));
+        // Only a form whose numbers are not increasing falls below its first number.
+        let Some(distance) = converted.checked_sub(base) else {
+            self.next = None;
+            return None;
+        };
+        Some(Pitch::new(class, distance / oct_len))
+    }
 }
 
 pub trait Distance<T> {
@@ -184,12 +524,69 @@ impl<S: Scale, Oct: Octave> Distance<&Pitch<Oct::PitchClass>> for Keyboard<Scale
         to: &Pitch<Oct::PitchClass>,
     ) -> Option<Self::Distance> {
         match (self.as_number(from), self.as_number(to)) {
-            (Some(from), Some(to)) => Some(Degree::new(to - from + 1).unwrap()),
+            (Some(from), Some(to)) => Degree::new(to.checked_sub(from)? + 1).ok(),
             (_, _) => None,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookupError {
+    Empty,
+    OutOfRange(usize),
+}
+
+impl Display for LookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LookupError::Empty => write!(f, "nothing can be looked up on an empty octave"),
+            LookupError::OutOfRange(n) => write!(f, "number {n} is out of range"),
+        }
+    }
+}
+
+impl Error for LookupError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleError {
+    EmptyOctave,
+    EmptyScale,
+    MissingKey,
+    MismatchedForms,
+    NotIncreasing(usize),
+    OutOfOctave(usize),
+    Lookup(LookupError),
+}
+
+impl From<LookupError> for ScaleError {
+    fn from(value: LookupError) -> Self {
+        ScaleError::Lookup(value)
+    }
+}
+
+impl Display for ScaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaleError::EmptyOctave => write!(f, "octave must not be empty"),
+            ScaleError::EmptyScale => write!(f, "scale must not be empty"),
+            ScaleError::MissingKey => write!(f, "scale must start from its key"),
+            ScaleError::MismatchedForms => {
+                write!(
+                    f,
+                    "ascending and descending forms must start on the same number"
+                )
+            }
+            ScaleError::NotIncreasing(i) => {
+                write!(f, "scale must be strictly increasing, but {i}th is not")
+            }
+            ScaleError::OutOfOctave(i) => write!(f, "{i}th of scale is out of the octave"),
+            ScaleError::Lookup(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for ScaleError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,6 +607,33 @@ mod tests {
         assert_eq!(key.get_pitch(&6_usize), Pitch::new(&0_usize, 1));
     }
 
+    #[test]
+    fn as_number() {
+        use crate::prelude::{AccidentalSymbol::*, Tone, ToneSymbol::*, Twelve};
+        let key = Keyboard::new(Twelve);
+        assert_eq!(key.as_number(&Pitch::new(Tone::new(C, Sharp), 4)), Some(49));
+        assert_eq!(key.as_number(&Pitch::new(Tone::written(C, Flat), 0)), None);
+        assert_eq!(key.as_number(&Pitch::new(Tone::written(B, Sharp), 3)), None);
+        let key = Keyboard::new(vec![5, 7, 9]);
+        assert_eq!(key.as_number(&Pitch::new(3, 0)), None);
+        assert_eq!(key.as_number(&Pitch::new(7, 1)), Some(4));
+    }
+
+    #[test]
+    fn nearest() {
+        let key = Keyboard::new(vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(key.ratio(&9_usize), Some(2.0_f64.powf(1.5)));
+        assert_eq!(key.nearest(1, 2.0_f64.powf(4.0 / 12.0)), Some(3));
+        assert_eq!(key.nearest(1, 2.0), Some(7));
+        let key = Keyboard::new(Vec::<usize>::new());
+        assert_eq!(key.ratio(&0_usize), None);
+        let key = Keyboard::new(Scaled::new(
+            vec![3, 4, 5, 6, 7, 8],
+            (0..12).collect::<Vec<_>>(),
+        ));
+        assert_eq!(key.nearest(0, 2.0_f64.powf(4.0 / 12.0)), Some(4));
+    }
+
     #[test]
     fn scaler() {
         let oct = vec![0, 1, 2, 3, 4, 5];
@@ -221,6 +645,48 @@ mod tests {
         assert_eq!(key.get_class(&3_usize), &0);
     }
 
+    #[test]
+    fn try_get_class() {
+        let key = Keyboard::new(Vec::<usize>::new());
+        assert_eq!(key.try_get_class(&0_usize), Err(LookupError::Empty));
+        let oct = HashMap::from([(0, 0), (2, 2)]);
+        let key = Keyboard::new(oct);
+        assert_eq!(key.try_get_class(&2_usize), Ok(&0));
+        assert_eq!(key.try_get_pitch(&1_usize), Err(LookupError::OutOfRange(1)));
+        let oct = HashMap::from([(0, 0), (2, 2)]);
+        assert!(matches!(
+            Keyboard::try_new(oct),
+            Err(LookupError::OutOfRange(1))
+        ));
+    }
+
+    #[test]
+    fn scaled_try_new() {
+        let oct = || vec![0, 1, 2, 3, 4, 5];
+        assert!(Scaled::try_new(vec![0, 2, 4], oct()).is_ok());
+        assert!(matches!(
+            Scaled::try_new(Vec::<usize>::new(), oct()),
+            Err(ScaleError::EmptyScale)
+        ));
+        assert!(matches!(
+            Scaled::try_new(vec![0, 2], Vec::<usize>::new()),
+            Err(ScaleError::EmptyOctave)
+        ));
+        assert!(matches!(
+            Scaled::try_new(vec![0, 4, 2], oct()),
+            Err(ScaleError::NotIncreasing(2))
+        ));
+        assert!(matches!(
+            Scaled::try_new(vec![1, 3, 7], oct()),
+            Err(ScaleError::OutOfOctave(2))
+        ));
+        let scaled = Scaled::new(vec![0, 2], oct());
+        assert_eq!(
+            scaled.try_get_class(&Cycle::new(3_usize, 4)),
+            Err(LookupError::OutOfRange(3))
+        );
+    }
+
     #[test]
     fn scaler_get_number() {
         let oct = vec![0, 1, 2, 3, 4, 5];
@@ -260,5 +726,46 @@ mod tests {
             Some(Degree::new(4).unwrap())
         );
         assert_eq!(key.measure(&Pitch::new(0, 0), &Pitch::new(1, 1)), None);
+        assert_eq!(key.measure(&Pitch::new(2, 0), &Pitch::new(0, 0)), None);
+    }
+
+    #[test]
+    fn frequency() {
+        let oct = vec![0, 1, 2, 3];
+        let key = Keyboard::new(oct);
+        assert_eq!(key.frequency(&Pitch::new(0, 0), 100.0), Some(100.0));
+        let freq = key.frequency(&Pitch::new(2, 1), 100.0).unwrap();
+        assert!((freq - 200.0 * 2.0_f64.sqrt()).abs() < 1e-9);
+        assert_eq!(key.frequency(&Pitch::new(4, 0), 100.0), None);
+        // The scale starts on the class 2, so the class 0 of the period 0 lies above it.
+        let key = Keyboard::new(Scaled::new(vec![2, 3, 4], vec![0, 1, 2, 3]));
+        assert_eq!(key.frequency(&Pitch::new(2, 0), 100.0), Some(100.0));
+        let freq = key.frequency(&Pitch::new(0, 0), 100.0).unwrap();
+        assert!((freq - 100.0 * 2.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn get_altered_pitch() {
+        let oct = vec![0, 1, 2, 3, 4, 5];
+        let scaler = vec![1, 3, 5];
+        let key = Keyboard::new(Scaled::new(scaler, oct));
+        let degree = |d, a| AlteredDegree::new(Degree::new(d).unwrap(), a);
+        assert_eq!(
+            key.get_altered_pitch(&degree(2, 0)),
+            Some(Pitch::new(&3, 0))
+        );
+        assert_eq!(
+            key.get_altered_pitch(&degree(2, -1)),
+            Some(Pitch::new(&2, 0))
+        );
+        assert_eq!(
+            key.get_altered_pitch(&degree(3, 1)),
+            Some(Pitch::new(&0, 0))
+        );
+        assert_eq!(
+            key.get_altered_pitch(&degree(4, 1)),
+            Some(Pitch::new(&2, 1))
+        );
+        assert_eq!(key.get_altered_pitch(&degree(1, -1)), None);
     }
 }
@@ -7,5 +7,4 @@ mod pitch;
 pub use chord::*;
 pub use distance::*;
 pub use keyboard::*;
-pub use number::*;
 pub use pitch::*;
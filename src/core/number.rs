@@ -31,10 +31,18 @@ impl Cycle {
 }
 
 impl Cycle {
+    /// Returns a value within `0..len`.
+    /// # Panics
+    /// - if `len` is 0. Use `try_value` for a cycle that may be empty.
     pub fn value(&self) -> usize {
         self.inner.value() % self.len
     }
 
+    /// Returns a value within `0..len`, or `None` if `len` is 0.
+    pub fn try_value(&self) -> Option<usize> {
+        self.inner.value().checked_rem(self.len)
+    }
+
     pub fn increment(&mut self, value: usize) {
         self.inner += value;
     }
@@ -1,3 +1,7 @@
+use super::{Keyboard, Octave};
+
+/// A pitch class placed in a period of an `Octave`.
+/// - `oct` is an index of the period, which is an octave on most tunings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Pitch<T> {
     class: T,
@@ -17,6 +21,11 @@ impl<T> Pitch<T> {
         self.oct
     }
 
+    /// Returns an index of the period, same as [`Pitch::oct`].
+    pub fn period(&self) -> usize {
+        self.oct
+    }
+
     pub fn as_ref(&self) -> Pitch<&T> {
         Pitch::new(self.class(), self.oct())
     }
@@ -31,10 +40,37 @@ impl<T: Clone> Pitch<&T> {
     }
 }
 
-// impl<T: PitchClassLike> Pitch<T> {
-//     pub fn frequency(&self, ref_freq: f32) -> f32 {
-//         ref_freq
-//             * self.oct() as f32
-//             * 2.0_f32.powf(self.class().step() as f32 / self.class().chroma_size() as f32)
-//     }
-// }
+/// A range of pitches from `low` to `high`, both inclusive, like a range of an instrument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PitchRange<T> {
+    low: Pitch<T>,
+    high: Pitch<T>,
+}
+
+impl<T> PitchRange<T> {
+    pub fn new(low: Pitch<T>, high: Pitch<T>) -> Self {
+        Self { low, high }
+    }
+
+    pub fn low(&self) -> &Pitch<T> {
+        &self.low
+    }
+
+    pub fn high(&self) -> &Pitch<T> {
+        &self.high
+    }
+
+    /// Returns `true` if `pitch` lies in this range on `keyboard`.
+    /// - Returns `false` if any pitch is not found on `keyboard`.
+    pub fn contains<O: Octave<PitchClass = T>>(
+        &self,
+        pitch: &Pitch<T>,
+        keyboard: &Keyboard<O>,
+    ) -> bool {
+        let number = |p| keyboard.as_number(p);
+        match (number(&self.low), number(pitch), number(&self.high)) {
+            (Some(low), Some(pitch), Some(high)) => low <= pitch && pitch <= high,
+            _ => false,
+        }
+    }
+}
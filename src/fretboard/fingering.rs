@@ -0,0 +1,326 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    chord::voicing::{class, number},
+    prelude::{Chord, Fretboard, Pitch, Tone, Voicer, Voicing},
+};
+
+/// Frets to stop on each string, where `None` is a muted string and 0 is an open string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Fingering {
+    frets: Vec<Option<usize>>,
+}
+
+impl Fingering {
+    pub fn new(frets: Vec<Option<usize>>) -> Self {
+        Self { frets }
+    }
+
+    pub fn frets(&self) -> &[Option<usize>] {
+        &self.frets
+    }
+
+    /// Returns sounding pitches on `fretboard`, from the lowest string.
+    pub fn pitches(&self, fretboard: &Fretboard) -> Vec<Pitch<Tone>> {
+        self.frets
+            .iter()
+            .enumerate()
+            .filter_map(|(string, fret)| fretboard.pitch(string, (*fret)?))
+            .collect()
+    }
+
+    /// Returns the lowest and the highest fret stopped by fingers, which excludes open strings.
+    /// - Returns `None` if no string is stopped.
+    pub fn stretch(&self) -> Option<(usize, usize)> {
+        let stopped = self.frets.iter().flatten().filter(|f| **f > 0);
+        Some((*stopped.clone().min()?, *stopped.max()?))
+    }
+
+    /// Returns the number of fingers needed, where a barre of the lowest stopped fret
+    /// counts as one finger.
+    pub fn fingers(&self) -> usize {
+        fingers(&self.frets)
+    }
+}
+
+/// Returns the number of fingers that `frets` need, as `Fingering::fingers` does.
+/// - It never decreases as frets are added.
+fn fingers(frets: &[Option<usize>]) -> usize {
+    let stopped = frets.iter().flatten().filter(|f| **f > 0);
+    let Some(lowest) = stopped.clone().min() else {
+        return 0;
+    };
+    1 + stopped.filter(|f| *f != lowest).count()
+}
+
+/// Writes frets from the lowest string with "x" for muted strings, like "x32010",
+/// or separated by hyphens if any fret has 2 digits, like "x-10-12-12-12-10".
+impl Display for Fingering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let frets: Vec<String> = self
+            .frets
+            .iter()
+            .map(|fret| fret.map_or("x".to_string(), |f| f.to_string()))
+            .collect();
+        let separator = if frets.iter().any(|f| f.len() > 1) {
+            "-"
+        } else {
+            ""
+        };
+        write!(f, "{}", frets.join(separator))
+    }
+}
+
+/// Finds playable fingerings of chords on a `Fretboard`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerer {
+    span: usize,
+    open: bool,
+    fingers: usize,
+}
+
+impl Default for Fingerer {
+    fn default() -> Self {
+        Self {
+            span: 4,
+            open: true,
+            fingers: 4,
+        }
+    }
+}
+
+impl Fingerer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the stopped frets within `span` frets, which is 4 by default.
+    pub fn with_span(self, span: usize) -> Self {
+        Self {
+            span: span.max(1),
+            ..self
+        }
+    }
+
+    /// Allows open strings, which is `true` by default.
+    pub fn allow_open(self, open: bool) -> Self {
+        Self { open, ..self }
+    }
+
+    /// Limits the number of fingers, which is 4 by default.
+    pub fn with_fingers(self, fingers: usize) -> Self {
+        Self { fingers, ..self }
+    }
+
+    pub fn span(&self) -> usize {
+        self.span
+    }
+
+    /// Returns fingerings of `chord` on `fretboard`, from the easiest.
+    /// - The lowest sounding pitch is the bass of a slash chord, or the root otherwise.
+    /// - Every chord tone sounds, except the 5th and extensions that are omitted when
+    ///   the chord has more tones than the strings.
+    /// - Fingerings with fewer muted strings between sounding strings come first, then
+    ///   those in a lower hand position, with more sounding strings, on lower frets and
+    ///   with more open strings.
+    pub fn fingerings(&self, chord: &Chord<Tone>, fretboard: &Fretboard) -> Vec<Fingering> {
+        let strings = fretboard.strings().len();
+        let bass = class(chord.bass().unwrap_or(chord.root()));
+        let mut allowed: Vec<usize> = Voicer::new(Voicing::Close)
+            .tones(chord)
+            .iter()
+            .map(|(_, t)| class(t))
+            .collect();
+        allowed.push(bass);
+        let mut required: Vec<usize> = Voicer::new(Voicing::Close)
+            .with_voices(strings.saturating_sub(chord.bass().is_some() as usize))
+            .tones(chord)
+            .iter()
+            .map(|(_, t)| class(t))
+            .collect();
+        required.push(bass);
+
+        let mut search = Search {
+            fingers: self.fingers,
+            bass,
+            required: required.iter().fold(0, |bits, c| bits | 1 << c),
+            options: vec![],
+            reachable: vec![],
+            lowest_bass: vec![],
+            frets: vec![],
+            seen: HashSet::new(),
+            fingerings: vec![],
+        };
+        let highest = fretboard
+            .playable_frets()
+            .saturating_sub(self.span - 1)
+            .max(1);
+        for low in 1..=highest {
+            let options = (0..strings)
+                .map(|string| {
+                    let frets = (low..low + self.span).filter(|f| *f <= fretboard.playable_frets());
+                    let open = Some(0).filter(|_| self.open);
+                    let sounding = open.into_iter().chain(frets).filter_map(|fret| {
+                        let pitch = fretboard.pitch(string, fret)?;
+                        let tone = (number(&pitch), class(pitch.class()));
+                        allowed
+                            .contains(&tone.1)
+                            .then_some((Some(fret), Some(tone)))
+                    });
+                    std::iter::once((None, None)).chain(sounding).collect()
+                })
+                .collect();
+            search.run(options);
+        }
+        let mut fingerings = search.fingerings;
+        fingerings.sort_by_key(|fingering| {
+            let sounding: Vec<usize> = (0..strings)
+                .filter(|s| fingering.frets[*s].is_some())
+                .collect();
+            let inner_muted = match (sounding.first(), sounding.last()) {
+                (Some(first), Some(last)) => last - first + 1 - sounding.len(),
+                _ => 0,
+            };
+            let position = fingering.stretch().map_or(0, |(_, high)| high);
+            let open = fingering.frets.iter().filter(|f| **f == Some(0)).count();
+            // Positions are compared by spans, so that a full chord is preferred to a part
+            // of it in the same hand position.
+            let span = position.saturating_sub(1) / self.span;
+            let muted = strings - sounding.len();
+            (inner_muted, span, muted, position, strings - open)
+        });
+        fingerings
+    }
+}
+
+/// A fret to choose on a string, with the number and the class of its pitch if it sounds.
+type Choice = (Option<usize>, Option<(usize, usize)>);
+
+/// A depth-first search of fingerings from the lowest string, which cuts a branch as soon
+/// as it cannot sound the bass lowest, sound every required class, or be fingered.
+struct Search {
+    fingers: usize,
+    bass: usize,
+    /// Classes that must sound, as bits.
+    required: u16,
+    options: Vec<Vec<Choice>>,
+    /// Classes that the strings from each one on can sound, as bits.
+    reachable: Vec<u16>,
+    /// The lowest number of the bass that the strings from each one on can sound.
+    lowest_bass: Vec<Option<usize>>,
+    frets: Vec<Option<usize>>,
+    seen: HashSet<Fingering>,
+    fingerings: Vec<Fingering>,
+}
+
+impl Search {
+    /// Collects fingerings of `options` on each string that are not found yet.
+    fn run(&mut self, options: Vec<Vec<Choice>>) {
+        let strings = options.len();
+        self.reachable = vec![0; strings + 1];
+        self.lowest_bass = vec![None; strings + 1];
+        for (string, choices) in options.iter().enumerate().rev() {
+            let mut reachable = self.reachable[string + 1];
+            let mut lowest_bass = self.lowest_bass[string + 1];
+            for (number, class) in choices.iter().filter_map(|(_, tone)| *tone) {
+                reachable |= 1 << class;
+                if class == self.bass && lowest_bass.is_none_or(|n| number < n) {
+                    lowest_bass = Some(number);
+                }
+            }
+            self.reachable[string] = reachable;
+            self.lowest_bass[string] = lowest_bass;
+        }
+        self.options = options;
+        self.collect(0, None);
+    }
+
+    /// - `covered` is classes that the chosen frets sound, as bits.
+    /// - `lowest` is the number and the class of the lowest pitch that they sound.
+    fn collect(&mut self, covered: u16, lowest: Option<(usize, usize)>) {
+        let string = self.frets.len();
+        if self.required & !(covered | self.reachable[string]) != 0
+            || fingers(&self.frets) > self.fingers
+        {
+            return;
+        }
+        // A lowest pitch other than the bass needs the bass below it on a later string.
+        if let Some((number, class)) = lowest {
+            if class != self.bass && self.lowest_bass[string].is_none_or(|n| n >= number) {
+                return;
+            }
+        }
+        if string == self.options.len() {
+            let fingering = Fingering::new(self.frets.clone());
+            if lowest.is_some() && self.seen.insert(fingering.clone()) {
+                self.fingerings.push(fingering);
+            }
+            return;
+        }
+        for i in 0..self.options[string].len() {
+            let (fret, tone) = self.options[string][i];
+            let lowest = match (lowest, tone) {
+                (Some(lowest), Some(tone)) if tone.0 >= lowest.0 => Some(lowest),
+                (lowest, None) => lowest,
+                (_, tone) => tone,
+            };
+            self.frets.push(fret);
+            self.collect(covered | tone.map_or(0, |(_, class)| 1 << class), lowest);
+            self.frets.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn first(chord: &str, fretboard: &Fretboard) -> String {
+        let chord: Chord<Tone> = chord.parse().unwrap();
+        Fingerer::new().fingerings(&chord, fretboard)[0].to_string()
+    }
+
+    #[test]
+    fn open_chords() {
+        let guitar = Fretboard::standard();
+        assert_eq!(first("C", &guitar), "x32010");
+        assert_eq!(first("Em", &guitar), "022000");
+        assert_eq!(first("D", &guitar), "xx0232");
+        assert_eq!(first("C/G", &guitar), "332010");
+        assert_eq!(first("C", &Fretboard::ukulele()), "0003");
+    }
+
+    #[test]
+    fn extended() {
+        let guitar = Fretboard::standard();
+        assert_eq!(first("C13", &guitar), "857056");
+        assert_eq!(first("G7(b9,#11)", &guitar), "320121");
+    }
+
+    #[test]
+    fn constraints() {
+        let guitar = Fretboard::standard();
+        let chord: Chord<Tone> = "F".parse().unwrap();
+        let fingerings = Fingerer::new()
+            .allow_open(false)
+            .fingerings(&chord, &guitar);
+        assert!(fingerings.iter().all(|f| !f.frets().contains(&Some(0))));
+        assert_eq!(fingerings[0].to_string(), "133211");
+        let narrow = Fingerer::new().with_span(2).fingerings(&chord, &guitar);
+        assert!(narrow
+            .iter()
+            .all(|f| f.stretch().is_none_or(|(low, high)| high - low < 2)));
+        let capo = guitar.with_capo(2);
+        let pitches = Fingerer::new().fingerings(&chord, &capo)[0].pitches(&capo);
+        assert!(pitches
+            .iter()
+            .all(|p| [5, 9, 0].contains(&(usize::from(*p.class()) % 12))));
+    }
+
+    #[test]
+    fn display() {
+        let fingering = Fingering::new(vec![None, Some(10), Some(12), Some(12), Some(12), None]);
+        assert_eq!(fingering.to_string(), "x-10-12-12-12-x");
+        assert_eq!(fingering.fingers(), 4);
+    }
+}
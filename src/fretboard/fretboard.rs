@@ -0,0 +1,155 @@
+use crate::{chord::voicing::number, prelude::*};
+
+/// A fretted instrument, whose strings are tuned to open pitches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fretboard {
+    strings: Vec<Pitch<Tone>>,
+    frets: usize,
+    capo: usize,
+}
+
+impl Fretboard {
+    /// Returns a `Fretboard` whose strings are tuned to `strings` with `frets` frets.
+    /// - Strings are ordered from the one nearest to the thumb, like the low E of a guitar.
+    pub fn new(strings: Vec<Pitch<Tone>>, frets: usize) -> Self {
+        Self {
+            strings,
+            frets,
+            capo: 0,
+        }
+    }
+
+    /// Returns a guitar in the standard tuning, E2 A2 D3 G3 B3 E4.
+    pub fn standard() -> Self {
+        Self::tuned(
+            &[("E", 2), ("A", 2), ("D", 3), ("G", 3), ("B", 3), ("E", 4)],
+            22,
+        )
+    }
+
+    /// Returns a guitar whose lowest string is lowered to D2.
+    pub fn drop_d() -> Self {
+        Self::tuned(
+            &[("D", 2), ("A", 2), ("D", 3), ("G", 3), ("B", 3), ("E", 4)],
+            22,
+        )
+    }
+
+    /// Returns a guitar in the DADGAD tuning, D2 A2 D3 G3 A3 D4.
+    pub fn dadgad() -> Self {
+        Self::tuned(
+            &[("D", 2), ("A", 2), ("D", 3), ("G", 3), ("A", 3), ("D", 4)],
+            22,
+        )
+    }
+
+    /// Returns a 4-string bass, E1 A1 D2 G2.
+    pub fn bass() -> Self {
+        Self::tuned(&[("E", 1), ("A", 1), ("D", 2), ("G", 2)], 20)
+    }
+
+    /// Returns a ukulele in the re-entrant tuning, G4 C4 E4 A4.
+    pub fn ukulele() -> Self {
+        Self::tuned(&[("G", 4), ("C", 4), ("E", 4), ("A", 4)], 15)
+    }
+
+    /// Returns a mandolin, G3 D4 A4 E5, whose courses count as single strings.
+    pub fn mandolin() -> Self {
+        Self::tuned(&[("G", 3), ("D", 4), ("A", 4), ("E", 5)], 17)
+    }
+
+    fn tuned(strings: &[(&str, usize)], frets: usize) -> Self {
+        let strings = strings
+            .iter()
+            .map(|(tone, oct)| Pitch::new(tone.parse().unwrap(), *oct))
+            .collect();
+        Self::new(strings, frets)
+    }
+
+    pub fn with_frets(self, frets: usize) -> Self {
+        Self { frets, ..self }
+    }
+
+    /// Returns a `Fretboard` with a capo on `capo`-th fret.
+    /// - Frets are counted from the capo afterward, so 0 is the capoed string.
+    pub fn with_capo(self, capo: usize) -> Self {
+        Self { capo, ..self }
+    }
+
+    /// Returns open pitches of the strings, without the capo.
+    pub fn strings(&self) -> &[Pitch<Tone>] {
+        &self.strings
+    }
+
+    pub fn frets(&self) -> usize {
+        self.frets
+    }
+
+    pub fn capo(&self) -> usize {
+        self.capo
+    }
+
+    /// Returns the number of frets above the capo.
+    pub fn playable_frets(&self) -> usize {
+        self.frets.saturating_sub(self.capo)
+    }
+
+    /// Returns a pitch of `string` stopped at `fret`, which is counted from the capo.
+    /// - Black keys are spelled with sharps.
+    /// - Returns `None` if `string` or `fret` is out of this `Fretboard`.
+    pub fn pitch(&self, string: usize, fret: usize) -> Option<Pitch<Tone>> {
+        if fret > self.playable_frets() {
+            return None;
+        }
+        let number = number(self.strings.get(string)?) + self.capo + fret;
+        Some(Pitch::new(
+            Tone::spell(number % 12, AccidentalSymbol::Sharp),
+            number / 12,
+        ))
+    }
+
+    /// Returns pairs of a string and a fret that sound `pitch`, from the lowest string.
+    pub fn positions(&self, pitch: &Pitch<Tone>) -> Vec<(usize, usize)> {
+        let target = number(pitch);
+        self.strings
+            .iter()
+            .enumerate()
+            .filter_map(|(string, open)| {
+                let fret = target.checked_sub(number(open) + self.capo)?;
+                (fret <= self.playable_frets()).then_some((string, fret))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn pitch(tone: &str, oct: usize) -> Pitch<Tone> {
+        Pitch::new(tone.parse().unwrap(), oct)
+    }
+
+    #[test]
+    fn pitch_of_fret() {
+        let guitar = Fretboard::standard();
+        assert_eq!(guitar.pitch(0, 0), Some(pitch("E", 2)));
+        assert_eq!(guitar.pitch(1, 3), Some(pitch("C", 3)));
+        assert_eq!(guitar.pitch(5, 23), None);
+        assert_eq!(guitar.pitch(6, 0), None);
+        let guitar = guitar.with_capo(2);
+        assert_eq!(guitar.pitch(0, 0), Some(pitch("Fs", 2)));
+        assert_eq!(guitar.playable_frets(), 20);
+    }
+
+    #[test]
+    fn positions() {
+        let guitar = Fretboard::standard();
+        assert_eq!(
+            guitar.positions(&pitch("E", 4)),
+            vec![(1, 19), (2, 14), (3, 9), (4, 5), (5, 0)]
+        );
+        assert_eq!(Fretboard::ukulele().positions(&pitch("C", 4)), vec![(1, 0)]);
+        assert!(Fretboard::bass().positions(&pitch("C", 1)).is_empty());
+    }
+}
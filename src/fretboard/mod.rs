@@ -0,0 +1,6 @@
+pub mod fingering;
+#[allow(clippy::module_inception)]
+pub mod fretboard;
+
+pub use fingering::*;
+pub use fretboard::*;
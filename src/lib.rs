@@ -2,8 +2,10 @@
 
 pub mod chord;
 pub mod core;
+pub mod fretboard;
 pub mod parse;
 pub mod scale;
+pub mod tuning;
 pub mod twelve_tet;
 
 pub mod prelude;
@@ -0,0 +1,432 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use once_cell::sync::Lazy;
+
+use crate::prelude::{AccidentalSymbol, Chord, Quality, Tension, Tensions, Tone, ToneSymbol};
+
+/// Quality symbols on lead sheets and their synonyms.
+static SYMBOL_TO_QUALITY: Lazy<Vec<(&str, Quality)>> = Lazy::new(|| {
+    use Quality::*;
+    let mut symbols = vec![];
+    let mut insert = |names: &[&'static str], quality: Quality| {
+        names.iter().for_each(|n| symbols.push((*n, quality)));
+    };
+    insert(&["maj", "Maj", "M", "ma", "major"], Major);
+    insert(&["m", "min", "mi", "-", "minor"], Minor);
+    insert(&["dim", "°", "o"], Dim);
+    insert(&["aug", "+", "+5"], Aug);
+    insert(&["sus2"], Sus2);
+    insert(&["sus4", "sus"], Sus4);
+    insert(&["5"], Power);
+    insert(&["6", "maj6", "M6", "add6"], Major6);
+    insert(&["m6", "min6", "mi6", "-6"], Minor6);
+    insert(&["69", "6/9", "6add9"], SixNine);
+    insert(
+        &["m69", "m6/9", "min6/9", "mi6/9", "-69", "-6/9"],
+        MinorSixNine,
+    );
+    insert(
+        &["maj7", "Maj7", "M7", "ma7", "Δ", "Δ7", "∆", "∆7", "^", "^7"],
+        Major7,
+    );
+    insert(&["m7", "min7", "mi7", "-7"], Minor7);
+    insert(&["7", "dom7"], Dominant7);
+    insert(
+        &[
+            "m7b5",
+            "m7♭5",
+            "min7b5",
+            "mi7b5",
+            "-7b5",
+            "-7♭5",
+            "m7(b5)",
+            "m7(♭5)",
+            "mi7(b5)",
+            "mi7(♭5)",
+            "ø",
+            "ø7",
+            "Ø",
+            "Ø7",
+        ],
+        HalfDim7,
+    );
+    insert(&["dim7", "°7", "o7"], Dim7);
+    insert(
+        &[
+            "mMaj7",
+            "mmaj7",
+            "mM7",
+            "m(maj7)",
+            "m(Maj7)",
+            "min(maj7)",
+            "mi(maj7)",
+            "minMaj7",
+            "mΔ",
+            "mΔ7",
+            "m∆",
+            "m∆7",
+            "m^7",
+            "-maj7",
+            "-Δ",
+            "-Δ7",
+            "-∆",
+            "-∆7",
+            "-^7",
+        ],
+        MinorMajor7,
+    );
+    insert(&["aug7", "+7", "7#5", "7♯5", "7+5", "7+"], Aug7);
+    insert(
+        &[
+            "maj7#5", "maj7♯5", "maj7+5", "M7#5", "M7+5", "augmaj7", "augMaj7", "+maj7", "+M7",
+            "+Δ", "+Δ7", "+∆", "+∆7", "Δ7#5", "Δ#5", "∆7#5", "∆#5", "^7#5",
+        ],
+        AugMajor7,
+    );
+    insert(&["7sus4", "7sus", "sus7"], Dominant7Sus4);
+    insert(&["add9", "add2", "(add9)", "2"], Add9);
+    insert(
+        &["madd9", "m(add9)", "mi(add9)", "minadd9", "-add9", "m2"],
+        MinorAdd9,
+    );
+    insert(&["maj9", "Maj9", "M9", "ma9", "Δ9", "∆9", "^9"], Major9);
+    insert(&["m9", "min9", "mi9", "-9"], Minor9);
+    insert(&["9", "dom9"], Dominant9);
+    insert(&["11", "dom11"], Dominant11);
+    insert(&["m11", "min11", "mi11", "-11"], Minor11);
+    insert(
+        &["maj13", "Maj13", "M13", "ma13", "Δ13", "∆13", "^13"],
+        Major13,
+    );
+    insert(&["m13", "min13", "mi13", "-13"], Minor13);
+    insert(&["13", "dom13"], Dominant13);
+    insert(&["alt", "7alt", "alt7"], Altered);
+    symbols
+});
+
+/// Extended suspended chords, which are 7sus4 chords with implied tensions.
+static EXTENDED_SUS: Lazy<Vec<(&str, Tensions)>> = Lazy::new(|| {
+    use Tension::*;
+    let nine = Tensions::new().with(Nine);
+    let thirteen = nine.with(Thirteen);
+    vec![
+        ("9sus4", nine),
+        ("9sus", nine),
+        ("13sus4", thirteen),
+        ("13sus", thirteen),
+    ]
+});
+
+/// Parses a chord symbol on lead sheets, like "Cmaj7", "C-7", "G7(b9,#11)" or "C/E".
+/// - The root and the bass are spelled by an uppercase letter with "#", "♯", "b" or "♭".
+/// - Tensions follow the quality, either bare or in parentheses, separated by commas or spaces.
+/// - "9sus4" and "13sus4" are 7sus4 chords with the 9th, and with the 9th and the 13th.
+impl FromStr for Chord<Tone> {
+    type Err = ParseChordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let offset = s.len() - s.trim_start().len();
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseChordError::new(ParseChordErrorKind::Empty, offset, ""));
+        }
+        let (root, rest) = parse_tone(s)
+            .ok_or_else(|| ParseChordError::at(ParseChordErrorKind::InvalidRoot, s, 0, offset))?;
+        let mut position = offset + s.len() - rest.len();
+
+        // "6/9" is a quality, so a slash followed by a letter is only taken as a bass.
+        let (body, bass) = match rest.rsplit_once('/') {
+            Some((body, bass)) if !bass.starts_with(|c: char| c.is_ascii_digit()) => {
+                let start = offset + s.len() - bass.len();
+                let tone = match parse_tone(bass) {
+                    Some((tone, "")) => tone,
+                    _ => {
+                        return Err(ParseChordError::at(
+                            ParseChordErrorKind::InvalidBass,
+                            bass,
+                            0,
+                            start,
+                        ))
+                    }
+                };
+                (body, Some(tone))
+            }
+            _ => (rest, None),
+        };
+
+        let (quality, length, implied) = EXTENDED_SUS
+            .iter()
+            .filter(|(symbol, _)| body.starts_with(symbol))
+            .max_by_key(|(symbol, _)| symbol.len())
+            .map(|(symbol, tensions)| (Quality::Dominant7Sus4, symbol.len(), *tensions))
+            .or_else(|| {
+                SYMBOL_TO_QUALITY
+                    .iter()
+                    .filter(|(symbol, _)| body.starts_with(symbol))
+                    .max_by_key(|(symbol, _)| symbol.len())
+                    .map(|(symbol, quality)| (*quality, symbol.len(), Tensions::new()))
+            })
+            .unwrap_or((Quality::Major, 0, Tensions::new()));
+        position += length;
+
+        let mut tensions = parse_tensions(&body[length..], position)?;
+        // An altered 9th like "C13sus4(b9)" takes the place of the implied 9th.
+        let altered = tensions.contains(Tension::FlatNine) || tensions.contains(Tension::SharpNine);
+        implied
+            .iter()
+            .filter(|t| !(altered && *t == Tension::Nine))
+            .for_each(|t| tensions.insert(t));
+        let chord = Chord::new(root, quality).with_tensions(tensions);
+        Ok(match bass {
+            Some(bass) => chord.with_bass(bass),
+            None => chord,
+        })
+    }
+}
+
+/// Parses a leading tone as written, like "Cb", and returns it with the rest of `s`.
+fn parse_tone(s: &str) -> Option<(Tone, &str)> {
+    let letter = s.chars().next().filter(|c| c.is_ascii_uppercase())?;
+    let symbol: ToneSymbol = letter.to_string().parse().ok()?;
+    let rest = &s[1..];
+    let (accidental, rest) = match rest.chars().next() {
+        Some(c @ ('#' | '♯' | 'b' | '♭')) => {
+            let accidental = match c {
+                '#' | '♯' => AccidentalSymbol::Sharp,
+                _ => AccidentalSymbol::Flat,
+            };
+            (accidental, &rest[c.len_utf8()..])
+        }
+        _ => (AccidentalSymbol::Natural, rest),
+    };
+    Some((Tone::written(symbol, accidental), rest))
+}
+
+/// Parses tensions like "b9#11", "(b9, #11)" or "add9".
+/// - `position` is the position of `s` in the whole symbol, which is used for errors.
+fn parse_tensions(s: &str, position: usize) -> Result<Tensions, ParseChordError> {
+    let mut tensions = Tensions::new();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < s.len() {
+        let rest = &s[i..];
+        let c = rest.chars().next().unwrap();
+        match c {
+            '(' => depth += 1,
+            ')' if depth > 0 => depth -= 1,
+            ',' | ' ' => {}
+            _ => {
+                let (tension, length) = parse_tension(rest).ok_or_else(|| {
+                    ParseChordError::at(ParseChordErrorKind::InvalidTension, s, i, position)
+                })?;
+                tensions.insert(tension);
+                i += length;
+                continue;
+            }
+        }
+        i += c.len_utf8();
+    }
+    if depth > 0 {
+        let start = s.rfind('(').unwrap();
+        return Err(ParseChordError::at(
+            ParseChordErrorKind::UnclosedParenthesis,
+            s,
+            start,
+            position,
+        ));
+    }
+    Ok(tensions)
+}
+
+/// Parses a leading tension and returns it with its length in bytes.
+fn parse_tension(s: &str) -> Option<(Tension, usize)> {
+    use Tension::*;
+    let (added, rest) = match s.strip_prefix("add") {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (alteration, rest) = match rest.chars().next()? {
+        c @ ('b' | '♭' | '-') => (-1, &rest[c.len_utf8()..]),
+        c @ ('#' | '♯' | '+') => (1, &rest[c.len_utf8()..]),
+        _ => (0, rest),
+    };
+    if added && alteration != 0 {
+        return None;
+    }
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let tension = match (alteration, &rest[..digits]) {
+        (-1, "5") => FlatFive,
+        (1, "5") => SharpFive,
+        (-1, "9") => FlatNine,
+        (0, "9") => Nine,
+        (1, "9") => SharpNine,
+        (0, "11") => Eleven,
+        (1, "11") => SharpEleven,
+        (-1, "13") => FlatThirteen,
+        (0, "13") => Thirteen,
+        _ => return None,
+    };
+    Some((tension, s.len() - rest.len() + digits))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseChordErrorKind {
+    Empty,
+    InvalidRoot,
+    InvalidTension,
+    InvalidBass,
+    UnclosedParenthesis,
+}
+
+/// An error of parsing a chord symbol, which points at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseChordError {
+    kind: ParseChordErrorKind,
+    position: usize,
+    token: String,
+}
+
+impl ParseChordError {
+    fn new(kind: ParseChordErrorKind, position: usize, token: &str) -> Self {
+        Self {
+            kind,
+            position,
+            token: token.to_string(),
+        }
+    }
+
+    /// Returns an error on the token that starts at `start` of `s`,
+    /// which is placed at `position` in the whole symbol.
+    fn at(kind: ParseChordErrorKind, s: &str, start: usize, position: usize) -> Self {
+        let token = &s[start..];
+        let end = token
+            .find(['(', ')', ',', ' ', '/'].as_slice())
+            .filter(|&end| end > 0)
+            .unwrap_or(token.len());
+        Self::new(kind, position + start, &token[..end])
+    }
+
+    pub fn kind(&self) -> ParseChordErrorKind {
+        self.kind
+    }
+
+    /// Returns a byte position of the offending token in the parsed string.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+impl Display for ParseChordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self.kind {
+            ParseChordErrorKind::Empty => return write!(f, "Empty chord symbol"),
+            ParseChordErrorKind::InvalidRoot => "Invalid root",
+            ParseChordErrorKind::InvalidTension => "Invalid quality or tension",
+            ParseChordErrorKind::InvalidBass => "Invalid bass",
+            ParseChordErrorKind::UnclosedParenthesis => "Unclosed parenthesis",
+        };
+        write!(f, "{message} '{}' at {}", self.token, self.position)
+    }
+}
+
+impl Error for ParseChordError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use super::ParseChordErrorKind;
+
+    fn chord(root: &str, quality: Quality) -> Chord<Tone> {
+        Chord::new(root.parse().unwrap(), quality)
+    }
+
+    #[test]
+    fn synonyms() {
+        let cases: [(&[&str], Quality); 8] = [
+            (&["Cmaj7", "C∆7", "CΔ", "CM7", "C^7"], Quality::Major7),
+            (&["C-7", "Cm7", "Cmin7", "Cmi7"], Quality::Minor7),
+            (
+                &["Cm7b5", "Cø", "Cø7", "C-7b5", "Cmin7b5"],
+                Quality::HalfDim7,
+            ),
+            (&["C°7", "Co7", "Cdim7"], Quality::Dim7),
+            (
+                &["CmMaj7", "Cm(maj7)", "C-∆7", "CmM7", "Cm∆"],
+                Quality::MinorMajor7,
+            ),
+            (&["C69", "C6/9", "C6add9"], Quality::SixNine),
+            (&["C", "Cmaj", "CM", "Cmajor"], Quality::Major),
+            (&["C+", "Caug", "C+5"], Quality::Aug),
+        ];
+        for (symbols, quality) in cases {
+            for symbol in symbols {
+                assert_eq!(symbol.parse(), Ok(chord("C", quality)), "{symbol}");
+            }
+        }
+        assert_eq!("Fsus4".parse(), Ok(chord("F", Quality::Sus4)));
+        assert_eq!("Bb13".parse(), Ok(chord("Bb", Quality::Dominant13)));
+        assert_eq!("Ebm".parse(), Ok(chord("Eb", Quality::Minor)));
+        let nine = chord("C", Quality::Dominant7Sus4).with_tension(Tension::Nine);
+        assert_eq!("C9sus4".parse(), Ok(nine));
+        assert_eq!("C9sus".parse(), Ok(nine));
+        let thirteen = nine.with_tension(Tension::Thirteen);
+        assert_eq!("C13sus4".parse(), Ok(thirteen));
+        let altered = chord("C", Quality::Dominant7Sus4)
+            .with_tension(Tension::FlatNine)
+            .with_tension(Tension::Thirteen);
+        assert_eq!("C13sus4(b9)".parse(), Ok(altered));
+    }
+
+    #[test]
+    fn tensions() {
+        let expected = chord("G", Quality::Dominant7)
+            .with_tension(Tension::FlatNine)
+            .with_tension(Tension::SharpEleven);
+        assert_eq!("G7(b9,#11)".parse(), Ok(expected));
+        assert_eq!("G7(b9, #11)".parse(), Ok(expected));
+        assert_eq!("G7b9#11".parse(), Ok(expected));
+        let expected = chord("C", Quality::Dominant7).with_tension(Tension::FlatFive);
+        assert_eq!("C7b5".parse(), Ok(expected));
+        let expected = chord("C", Quality::Minor7).with_tension(Tension::Eleven);
+        assert_eq!("Cm7add11".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn slash() {
+        let expected = chord("C", Quality::Major).with_bass("E".parse().unwrap());
+        assert_eq!("C/E".parse(), Ok(expected));
+        let expected = chord("D", Quality::SixNine).with_bass("F#".parse().unwrap());
+        assert_eq!("D6/9/F#".parse(), Ok(expected));
+        let expected =
+            Chord::new(Tone::written(C, Flat), Quality::Major).with_bass(Tone::written(E, Sharp));
+        assert_eq!("Cb/E#".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn errors() {
+        let error = "H7".parse::<Chord<Tone>>().unwrap_err();
+        assert_eq!(error.kind(), ParseChordErrorKind::InvalidRoot);
+        assert_eq!((error.position(), error.token()), (0, "H7"));
+
+        let error = "G7(b9,x11)".parse::<Chord<Tone>>().unwrap_err();
+        assert_eq!(error.kind(), ParseChordErrorKind::InvalidTension);
+        assert_eq!((error.position(), error.token()), (6, "x11"));
+
+        let error = "Cmaj7/X".parse::<Chord<Tone>>().unwrap_err();
+        assert_eq!(error.kind(), ParseChordErrorKind::InvalidBass);
+        assert_eq!((error.position(), error.token()), (6, "X"));
+
+        let error = "C7(b9".parse::<Chord<Tone>>().unwrap_err();
+        assert_eq!(error.kind(), ParseChordErrorKind::UnclosedParenthesis);
+        assert_eq!(error.position(), 2);
+        assert_eq!(
+            "".parse::<Chord<Tone>>().unwrap_err().kind(),
+            ParseChordErrorKind::Empty
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::prelude::{AlteredDegree, ChordFormula, Degree};
+
+/// Parses a chord formula of degrees like "1 b3 5 b7 11", or of interval names like
+/// "P1 M3 A5".
+/// - Tokens are separated by spaces or commas, and both forms may be mixed.
+/// - An interval name is a quality of "P", "M", "m", "A" or "d", followed by a number.
+impl FromStr for ChordFormula {
+    type Err = ParseChordFormulaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|t| !t.is_empty())
+            .collect();
+        if tokens.is_empty() {
+            return Err(ParseChordFormulaError::Empty);
+        }
+        let degrees = tokens
+            .iter()
+            .map(|token| match token.chars().next() {
+                Some('P' | 'M' | 'm' | 'A' | 'd') => parse_interval(token),
+                _ => token
+                    .parse::<AlteredDegree>()
+                    .map_err(|_| ParseChordFormulaError::InvalidDegree),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        ChordFormula::from_degrees(&degrees).ok_or(ParseChordFormulaError::InvalidDegree)
+    }
+}
+
+/// Parses an interval name like "m3" or "A11" into an altered degree.
+fn parse_interval(s: &str) -> Result<AlteredDegree, ParseChordFormulaError> {
+    let number = s.trim_start_matches(|c: char| !c.is_ascii_digit());
+    let quality = &s[..s.len() - number.len()];
+    let degree: Degree = number
+        .parse()
+        .map_err(|_| ParseChordFormulaError::InvalidInterval)?;
+    let perfect = matches!(degree.value() % 7, 1 | 4 | 5);
+    let alteration = match (quality, perfect) {
+        ("P", true) | ("M", false) => 0,
+        ("m", false) => -1,
+        ("d", true) => -1,
+        ("d", false) => -2,
+        ("A", _) => 1,
+        _ => return Err(ParseChordFormulaError::InvalidInterval),
+    };
+    Ok(AlteredDegree::new(degree, alteration))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseChordFormulaError {
+    Empty,
+    InvalidDegree,
+    InvalidInterval,
+}
+
+impl Display for ParseChordFormulaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ParseChordFormulaError::Empty => "Empty formula: at least one tone is required",
+            ParseChordFormulaError::InvalidDegree => {
+                "Invalid degree: degree like '3', 'b3' or '#11' above the root is accepted"
+            }
+            ParseChordFormulaError::InvalidInterval => {
+                "Invalid interval: interval like 'P5', 'M3', 'm7', 'A5' or 'd5' is accepted"
+            }
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl Error for ParseChordFormulaError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn parse() {
+        let intervals = |v: &[usize]| v.iter().map(|&i| i.into()).collect::<Vec<Interval>>();
+        let formula: ChordFormula = "1, b3, 5, b7, 11".parse().unwrap();
+        assert_eq!(formula.intervals(), intervals(&[3, 7, 10, 17]));
+        let formula: ChordFormula = "P1 M3 A5 d7".parse().unwrap();
+        assert_eq!(formula.intervals(), intervals(&[4, 8, 9]));
+        let formula: ChordFormula = "M3 m7 A9".parse().unwrap();
+        assert_eq!(formula.intervals(), intervals(&[4, 10, 15]));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            " ".parse::<ChordFormula>(),
+            Err(ParseChordFormulaError::Empty)
+        );
+        assert_eq!(
+            "1 x3".parse::<ChordFormula>(),
+            Err(ParseChordFormulaError::InvalidDegree)
+        );
+        assert_eq!(
+            "P1 P3".parse::<ChordFormula>(),
+            Err(ParseChordFormulaError::InvalidInterval)
+        );
+        assert_eq!(
+            "1 bb1".parse::<ChordFormula>(),
+            Err(ParseChordFormulaError::InvalidDegree)
+        );
+    }
+}
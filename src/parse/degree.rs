@@ -0,0 +1,79 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::prelude::{AlteredDegree, Degree};
+
+impl FromStr for Degree {
+    type Err = ParseDegreeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: usize = s.parse().map_err(|_| ParseDegreeError::InvalidDegree)?;
+        Degree::new(value).map_err(|_| ParseDegreeError::InvalidDegree)
+    }
+}
+
+/// Parses an altered degree like "b3", "#11" or "♭♭7".
+impl FromStr for AlteredDegree {
+    type Err = ParseDegreeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let degree = s.trim_start_matches(['b', '♭', '#', '♯']);
+        let alteration = s[..s.len() - degree.len()]
+            .chars()
+            .map(|c| if c == 'b' || c == '♭' { -1 } else { 1 })
+            .sum();
+        if !degree.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(ParseDegreeError::InvalidDegree);
+        }
+        Ok(AlteredDegree::new(degree.parse()?, alteration))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDegreeError {
+    InvalidDegree,
+}
+
+impl Display for ParseDegreeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ParseDegreeError::InvalidDegree => {
+                "Invalid degree: degree like '3', 'b3' or '#11' is accepted"
+            }
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl Error for ParseDegreeError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse::degree::ParseDegreeError,
+        prelude::{AlteredDegree, Degree},
+    };
+
+    #[test]
+    fn parse_degree() {
+        assert_eq!("3".parse(), Ok(Degree::new(3).unwrap()));
+        assert_eq!("0".parse::<Degree>(), Err(ParseDegreeError::InvalidDegree));
+        assert_eq!("x".parse::<Degree>(), Err(ParseDegreeError::InvalidDegree));
+    }
+
+    #[test]
+    fn parse_altered_degree() {
+        let degree = |d, a| AlteredDegree::new(Degree::new(d).unwrap(), a);
+        assert_eq!("5".parse(), Ok(degree(5, 0)));
+        assert_eq!("b3".parse(), Ok(degree(3, -1)));
+        assert_eq!("#11".parse(), Ok(degree(11, 1)));
+        assert_eq!("♭♭7".parse(), Ok(degree(7, -2)));
+        assert_eq!(
+            "b".parse::<AlteredDegree>(),
+            Err(ParseDegreeError::InvalidDegree)
+        );
+        assert_eq!(
+            "3b".parse::<AlteredDegree>(),
+            Err(ParseDegreeError::InvalidDegree)
+        );
+    }
+}
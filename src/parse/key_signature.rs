@@ -0,0 +1,116 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::prelude::{KeySignature, ParseToneError, Tone};
+
+const NUMBER_WORDS: [&str; 8] = ["no", "one", "two", "three", "four", "five", "six", "seven"];
+
+/// Parses a key signature like "3b", "two sharps", "no sharps or flats" or "F# minor".
+impl FromStr for KeySignature {
+    type Err = ParseKeySignatureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let lower = s.to_lowercase();
+        if lower == "0" || lower == "none" || lower == "no sharps or flats" {
+            return Ok(KeySignature::new(0).unwrap());
+        }
+        let digits = lower.trim_start_matches(|c: char| c.is_ascii_digit());
+        let (count, accidental) = if digits.len() < lower.len() {
+            let count = lower[..lower.len() - digits.len()]
+                .parse::<i32>()
+                .map_err(|_| ParseKeySignatureError::TooManyAccidentals)?;
+            (Some(count), digits.trim())
+        } else {
+            let (word, rest) = lower.split_once(' ').unwrap_or((&lower, ""));
+            let count = NUMBER_WORDS.iter().position(|&w| w == word);
+            (count.map(|c| c as i32), rest.trim())
+        };
+        if let Some(count) = count {
+            let sign = match accidental {
+                "#" | "♯" | "sharp" | "sharps" => 1,
+                "b" | "♭" | "flat" | "flats" => -1,
+                _ => return Err(ParseKeySignatureError::InvalidAccidental),
+            };
+            return KeySignature::new(sign * count)
+                .ok_or(ParseKeySignatureError::TooManyAccidentals);
+        }
+        let (tonic, mode) = match s.split_once(char::is_whitespace) {
+            Some((tonic, mode)) => (tonic, mode.trim().to_lowercase()),
+            None => match s.strip_suffix('m') {
+                Some(tonic) => (tonic, "minor".to_string()),
+                None => (s, "major".to_string()),
+            },
+        };
+        let tonic: Tone = tonic.parse().map_err(ParseKeySignatureError::InvalidTone)?;
+        match mode.as_str() {
+            "major" | "maj" => Ok(KeySignature::from_major(&tonic)),
+            "minor" | "min" => Ok(KeySignature::from_minor(&tonic)),
+            _ => Err(ParseKeySignatureError::InvalidMode),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseKeySignatureError {
+    InvalidTone(ParseToneError),
+    InvalidAccidental,
+    InvalidMode,
+    TooManyAccidentals,
+}
+
+impl Display for ParseKeySignatureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseKeySignatureError::InvalidTone(e) => write!(f, "{e}"),
+            ParseKeySignatureError::InvalidAccidental => {
+                write!(f, "Invalid accidental: 'sharps' or 'flats' is accepted")
+            }
+            ParseKeySignatureError::InvalidMode => {
+                write!(f, "Invalid mode: 'major' or 'minor' is accepted")
+            }
+            ParseKeySignatureError::TooManyAccidentals => {
+                write!(f, "A key signature has at most 7 accidentals")
+            }
+        }
+    }
+}
+
+impl Error for ParseKeySignatureError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse::key_signature::ParseKeySignatureError, prelude::KeySignature};
+
+    #[test]
+    fn parse_count() {
+        assert_eq!("3b".parse(), Ok(KeySignature::new(-3).unwrap()));
+        assert_eq!("2#".parse(), Ok(KeySignature::new(2).unwrap()));
+        assert_eq!("two sharps".parse(), Ok(KeySignature::new(2).unwrap()));
+        assert_eq!("One Flat".parse(), Ok(KeySignature::new(-1).unwrap()));
+        assert_eq!("7 flats".parse(), Ok(KeySignature::new(-7).unwrap()));
+        assert_eq!(
+            "no sharps or flats".parse(),
+            Ok(KeySignature::new(0).unwrap())
+        );
+        assert_eq!(
+            "8#".parse::<KeySignature>(),
+            Err(ParseKeySignatureError::TooManyAccidentals)
+        );
+        assert_eq!(
+            "3x".parse::<KeySignature>(),
+            Err(ParseKeySignatureError::InvalidAccidental)
+        );
+    }
+
+    #[test]
+    fn parse_key() {
+        assert_eq!("F# minor".parse(), Ok(KeySignature::new(3).unwrap()));
+        assert_eq!("Bb major".parse(), Ok(KeySignature::new(-2).unwrap()));
+        assert_eq!("Ebm".parse(), Ok(KeySignature::new(-6).unwrap()));
+        assert_eq!("A".parse(), Ok(KeySignature::new(3).unwrap()));
+        assert_eq!(
+            "A lydian".parse::<KeySignature>(),
+            Err(ParseKeySignatureError::InvalidMode)
+        );
+    }
+}
@@ -1,3 +1,15 @@
+mod chord;
+mod chord_formula;
+pub mod degree;
+pub mod key_signature;
+mod roman;
+pub mod scale;
 pub mod tone;
 
+pub use chord::*;
+pub use chord_formula::*;
+pub use degree::*;
+pub use key_signature::*;
+pub use roman::*;
+pub use scale::*;
 pub use tone::*;
@@ -0,0 +1,142 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::prelude::{AlteredDegree, Degree, Quality, RomanNumeral, Special};
+
+const NUMERALS: [&str; 7] = ["VII", "VI", "V", "IV", "III", "II", "I"];
+
+/// Parses a Roman numeral like "ii7", "V7/V", "bVI", "viiø7", "It+6", "N6" or "V65".
+/// - The case gives a major or a minor chord, and "°", "ø", "+" and "M" alter it.
+/// - A figure gives an inversion, like "6" and "64" for triads, or "65", "43" and "42"
+///   for seventh chords.
+impl FromStr for RomanNumeral {
+    type Err = ParseRomanNumeralError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((numeral, target)) = s.split_once('/') {
+            return Ok(numeral.parse::<RomanNumeral>()?.of(target.parse()?));
+        }
+        let special = match s {
+            // The Neapolitan in root position is just the major triad on the lowered 2nd.
+            "N" => return "bII".parse(),
+            "N6" => Some(Special::Neapolitan),
+            "It+6" | "It6" => Some(Special::Italian),
+            "Fr+6" | "Fr6" | "Fr43" => Some(Special::French),
+            "Ger+6" | "Ger6" | "Ger65" => Some(Special::German),
+            _ => None,
+        };
+        if let Some(special) = special {
+            return Ok(RomanNumeral::special(special));
+        }
+
+        let rest = s.trim_start_matches(['b', '♭', '#', '♯']);
+        let alteration = s[..s.len() - rest.len()]
+            .chars()
+            .map(|c| if c == 'b' || c == '♭' { -1 } else { 1 })
+            .sum();
+        let (index, numeral) = NUMERALS
+            .iter()
+            .enumerate()
+            .find(|(_, n)| rest.starts_with(**n) || rest.starts_with(&n.to_lowercase()))
+            .ok_or(ParseRomanNumeralError::InvalidNumeral)?;
+        let upper = rest.starts_with(numeral);
+        let rest = &rest[numeral.len()..];
+        let degree = AlteredDegree::new(Degree::new(7 - index).unwrap(), alteration);
+
+        let figure = rest.trim_start_matches(|c: char| !c.is_ascii_digit());
+        let symbol = &rest[..rest.len() - figure.len()];
+        let (size, inversion) = match figure {
+            "" => (3, 0),
+            "6" => (3, 1),
+            "64" => (3, 2),
+            "7" => (4, 0),
+            "65" => (4, 1),
+            "43" => (4, 2),
+            "42" | "2" => (4, 3),
+            "9" => (5, 0),
+            _ => return Err(ParseRomanNumeralError::InvalidFigure),
+        };
+
+        use Quality::*;
+        let quality = match (size, upper, symbol) {
+            (3, true, "") => Major,
+            (3, false, "") => Minor,
+            (3, false, "°" | "o") => Dim,
+            (3, true, "+") => Aug,
+            (4, true, "") => Dominant7,
+            (4, true, "M" | "maj" | "Δ" | "∆") => Major7,
+            (4, false, "") => Minor7,
+            (4, false, "M" | "maj" | "Δ" | "∆") => MinorMajor7,
+            (4, false, "ø" | "Ø") => HalfDim7,
+            (4, false, "°" | "o") => Dim7,
+            (4, true, "+") => Aug7,
+            (4, true, "+M" | "+maj" | "+Δ" | "+∆") => AugMajor7,
+            (5, true, "") => Dominant9,
+            (5, true, "M" | "maj" | "Δ" | "∆") => Major9,
+            (5, false, "") => Minor9,
+            _ => return Err(ParseRomanNumeralError::InvalidQuality),
+        };
+        Ok(RomanNumeral::new(degree, quality, inversion))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseRomanNumeralError {
+    InvalidNumeral,
+    InvalidQuality,
+    InvalidFigure,
+}
+
+impl Display for ParseRomanNumeralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ParseRomanNumeralError::InvalidNumeral => {
+                "Invalid numeral: numeral from 'I' to 'VII', in upper or lower case, is accepted"
+            }
+            ParseRomanNumeralError::InvalidQuality => {
+                "Invalid quality: '°', 'ø', '+' or 'M' on a numeral of the matching case is accepted"
+            }
+            ParseRomanNumeralError::InvalidFigure => {
+                "Invalid figure: '6', '64', '7', '65', '43', '42' or '9' is accepted"
+            }
+        };
+        write!(f, "{message}")
+    }
+}
+
+impl Error for ParseRomanNumeralError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn parse() {
+        let numeral: RomanNumeral = "bVI".parse().unwrap();
+        assert_eq!(numeral.degree(), "b6".parse().unwrap());
+        assert_eq!(numeral.quality(), Quality::Major);
+        let numeral: RomanNumeral = "viiø65".parse().unwrap();
+        assert_eq!(numeral.quality(), Quality::HalfDim7);
+        assert_eq!(numeral.inversion(), 1);
+        let numeral: RomanNumeral = "V7/V".parse().unwrap();
+        assert_eq!(numeral.secondary(), Some(&"V".parse().unwrap()));
+        let numeral: RomanNumeral = "Ger+6".parse().unwrap();
+        assert_eq!(numeral.get_special(), Some(Special::German));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            "X".parse::<RomanNumeral>(),
+            Err(ParseRomanNumeralError::InvalidNumeral)
+        );
+        assert_eq!(
+            "Iø7".parse::<RomanNumeral>(),
+            Err(ParseRomanNumeralError::InvalidQuality)
+        );
+        assert_eq!(
+            "V8".parse::<RomanNumeral>(),
+            Err(ParseRomanNumeralError::InvalidFigure)
+        );
+    }
+}
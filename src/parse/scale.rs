@@ -0,0 +1,254 @@
+use std::{collections::HashMap, error::Error, fmt::Display, str::FromStr};
+
+use once_cell::sync::Lazy;
+
+use crate::prelude::{
+    AlteredDegree, Formula, Keyboard, ParseToneError, ScaleKind, Scaled, Step, StepPattern, Tone,
+    Twelve,
+};
+
+static NAME_TO_KIND: Lazy<HashMap<&'static str, ScaleKind>> = Lazy::new(|| {
+    use ScaleKind::*;
+    let mut hash = HashMap::<&'static str, ScaleKind>::default();
+    hash.insert("major", Major);
+    hash.insert("ionian", Major);
+    hash.insert("minor", NaturalMinor);
+    hash.insert("natural minor", NaturalMinor);
+    hash.insert("aeolian", NaturalMinor);
+    hash.insert("harmonic minor", HarmonicMinor);
+    hash.insert("melodic minor", MelodicMinor);
+    hash.insert("jazz minor", MelodicMinor);
+    hash.insert("major pentatonic", MajorPentatonic);
+    hash.insert("pentatonic", MajorPentatonic);
+    hash.insert("minor pentatonic", MinorPentatonic);
+    hash.insert("dorian", Dorian);
+    hash.insert("mixolydian", Mixolydian);
+    hash.insert("lydian", Lydian);
+    hash.insert("phrygian", Phrygian);
+    hash.insert("locrian", Locrian);
+    hash.insert("blues", Blues);
+    hash.insert("lydian dominant", LydianDominant);
+    hash.insert("altered", Altered);
+    hash.insert("super locrian", Altered);
+    hash.insert("phrygian dominant", PhrygianDominant);
+    hash.insert("harmonic major", HarmonicMajor);
+    hash.insert("dorian b2", DorianFlat2);
+    hash.insert("lydian augmented", LydianAugmented);
+    hash.insert("mixolydian b6", MixolydianFlat6);
+    hash.insert("locrian #2", LocrianNatural2);
+    hash.insert("locrian natural 2", LocrianNatural2);
+    hash.insert("whole tone", WholeTone);
+    hash.insert("half whole diminished", HalfWholeDiminished);
+    hash.insert("whole half diminished", WholeHalfDiminished);
+    hash.insert("diminished", WholeHalfDiminished);
+    hash.insert("chromatic", Chromatic);
+    hash
+});
+
+impl FromStr for ScaleKind {
+    type Err = ParseScaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s
+            .to_lowercase()
+            .replace(['-', '_'], " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        NAME_TO_KIND
+            .get(name.as_str())
+            .copied()
+            .ok_or(ParseScaleError::UnknownScale)
+    }
+}
+
+/// Parses a scale description like "D dorian", "Bb harmonic minor" or "Eb 1-2-b3-4-5-6-b7".
+impl FromStr for Formula {
+    type Err = ParseScaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (key, rest) = s.split_once(char::is_whitespace).unwrap_or((s, ""));
+        if key.is_empty() {
+            return Err(ParseScaleError::MissingTone);
+        }
+        let key: Tone = key.parse().map_err(ParseScaleError::InvalidTone)?;
+        let rest = rest.trim();
+        if rest.is_empty() {
+            return Err(ParseScaleError::UnknownScale);
+        }
+        let is_formula = rest
+            .trim_start_matches(['b', '♭', '#', '♯'])
+            .starts_with(|c: char| c.is_ascii_digit());
+        if !is_formula {
+            return Ok(Formula::named(&key, rest.parse()?));
+        }
+        let degrees = rest
+            .split(['-', ',', ' '])
+            .filter(|token| !token.is_empty())
+            .map(|token| token.parse::<AlteredDegree>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ParseScaleError::InvalidDegree)?;
+        Formula::from_degrees(&key, &degrees).ok_or(ParseScaleError::InvalidFormula)
+    }
+}
+
+/// Parses a step pattern like "LLsLLLs".
+impl FromStr for StepPattern {
+    type Err = ParseScaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                'L' => Ok(Step::Large),
+                's' => Ok(Step::Small),
+                _ => Err(ParseScaleError::InvalidStepPattern),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if steps.is_empty() {
+            return Err(ParseScaleError::InvalidStepPattern);
+        }
+        Ok(StepPattern::new(steps))
+    }
+}
+
+impl FromStr for Scaled<Formula, Twelve> {
+    type Err = ParseScaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Scaled::new(s.parse::<Formula>()?, Twelve))
+    }
+}
+
+impl FromStr for Keyboard<Scaled<Formula, Twelve>> {
+    type Err = ParseScaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Keyboard::new(s.parse::<Scaled<Formula, Twelve>>()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseScaleError {
+    MissingTone,
+    InvalidTone(ParseToneError),
+    UnknownScale,
+    InvalidDegree,
+    InvalidFormula,
+    InvalidStepPattern,
+}
+
+impl Display for ParseScaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseScaleError::MissingTone => write!(f, "Missing key of the scale"),
+            ParseScaleError::InvalidTone(e) => write!(f, "{e}"),
+            ParseScaleError::UnknownScale => write!(f, "Unknown scale name"),
+            ParseScaleError::InvalidDegree => {
+                write!(
+                    f,
+                    "Invalid degree: degree like '3', 'b3' or '#4' is accepted"
+                )
+            }
+            ParseScaleError::InvalidFormula => write!(
+                f,
+                "Invalid formula: degrees must be strictly increasing within an octave"
+            ),
+            ParseScaleError::InvalidStepPattern => {
+                write!(
+                    f,
+                    "Invalid step pattern: pattern like 'LLsLLLs' is accepted"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseScaleError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse::scale::ParseScaleError, prelude::*};
+
+    #[test]
+    fn parse_scale_kind() {
+        assert_eq!("Dorian".parse(), Ok(ScaleKind::Dorian));
+        assert_eq!("lydian-dominant".parse(), Ok(ScaleKind::LydianDominant));
+        assert_eq!("harmonic  minor".parse(), Ok(ScaleKind::HarmonicMinor));
+        assert_eq!(
+            "foo".parse::<ScaleKind>(),
+            Err(ParseScaleError::UnknownScale)
+        );
+    }
+
+    #[test]
+    fn parse_named() {
+        assert_eq!(
+            "Bb harmonic minor".parse(),
+            Ok(Formula::named(
+                &Tone::new(B, Flat),
+                ScaleKind::HarmonicMinor
+            ))
+        );
+        assert_eq!(
+            "F# lydian dominant".parse(),
+            Ok(Formula::named(
+                &Tone::new(F, Sharp),
+                ScaleKind::LydianDominant
+            ))
+        );
+        assert_eq!(
+            "H major".parse::<Formula>(),
+            Err(ParseScaleError::InvalidTone(ParseToneError::InvalidTone))
+        );
+        assert_eq!("C".parse::<Formula>(), Err(ParseScaleError::UnknownScale));
+    }
+
+    #[test]
+    fn parse_formula() {
+        let scale = "Eb 1-2-b3-4-5-6-b7".parse::<Formula>().unwrap();
+        assert_eq!(scale.key(), &Tone::new(E, Flat));
+        assert_eq!(scale.kind(), Some(ScaleKind::Dorian));
+        assert_eq!(
+            "C 1 b3 4 #4 5 b7".parse::<Formula>().map(|s| s.kind()),
+            Ok(Some(ScaleKind::Blues))
+        );
+        assert_eq!(
+            "C 1-3-2".parse::<Formula>(),
+            Err(ParseScaleError::InvalidFormula)
+        );
+        assert_eq!(
+            "C 2 3".parse::<Formula>(),
+            Err(ParseScaleError::InvalidFormula)
+        );
+        assert_eq!(
+            "C 1-x".parse::<Formula>(),
+            Err(ParseScaleError::InvalidDegree)
+        );
+    }
+
+    #[test]
+    fn parse_step_pattern() {
+        assert_eq!("LLsLLLs".parse(), Ok(StepPattern::major()));
+        assert_eq!("LsL LsLL".parse(), Ok(StepPattern::minor()));
+        assert_eq!(
+            "LLxs".parse::<StepPattern>(),
+            Err(ParseScaleError::InvalidStepPattern)
+        );
+        assert_eq!(
+            "".parse::<StepPattern>(),
+            Err(ParseScaleError::InvalidStepPattern)
+        );
+    }
+
+    #[test]
+    fn parse_keyboard() {
+        let keyboard: Keyboard<Scaled<Formula, Twelve>> = "D dorian".parse().unwrap();
+        let mut tones = keyboard.class_iter();
+        assert_eq!(tones.next(), Some(&"D".parse().unwrap()));
+        assert_eq!(tones.next(), Some(&"E".parse().unwrap()));
+        assert_eq!(tones.next(), Some(&"F".parse().unwrap()));
+    }
+}
@@ -46,8 +46,10 @@ impl FromStr for AccidentalSymbol {
         match s.to_ascii_lowercase().as_str() {
             "♯" => Ok(AccidentalSymbol::Sharp),
             "s" => Ok(AccidentalSymbol::Sharp),
+            "#" => Ok(AccidentalSymbol::Sharp),
             "♭" => Ok(AccidentalSymbol::Flat),
             "f" => Ok(AccidentalSymbol::Flat),
+            "b" => Ok(AccidentalSymbol::Flat),
             "♮" => Ok(AccidentalSymbol::Natural),
             "" => Ok(AccidentalSymbol::Natural),
             _ => Err(ParseToneError::InvalidAccidential),
@@ -95,6 +97,8 @@ mod tests {
         assert_eq!("s".parse(), Ok(AccidentalSymbol::Sharp));
         assert_eq!("♭".parse(), Ok(AccidentalSymbol::Flat));
         assert_eq!("f".parse(), Ok(AccidentalSymbol::Flat));
+        assert_eq!("#".parse(), Ok(AccidentalSymbol::Sharp));
+        assert_eq!("b".parse(), Ok(AccidentalSymbol::Flat));
         assert_eq!("♮".parse(), Ok(AccidentalSymbol::Natural));
         assert_eq!("".parse(), Ok(AccidentalSymbol::Natural));
         assert_eq!(
@@ -117,5 +121,9 @@ mod tests {
             "A♯".parse::<Tone>(),
             Ok(Tone::new(ToneSymbol::A, AccidentalSymbol::Sharp))
         );
+        assert_eq!(
+            "Bb".parse::<Tone>(),
+            Ok(Tone::new(ToneSymbol::B, AccidentalSymbol::Flat))
+        );
     }
 }
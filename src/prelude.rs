@@ -1,8 +1,12 @@
 pub use crate::chord::*;
 pub use crate::core::*;
+pub use crate::fretboard::*;
 pub use crate::parse::*;
 pub use crate::scale::*;
+pub use crate::tuning::*;
 pub use crate::twelve_tet::*;
 
+pub use crate::chord::Chord;
+
 pub use AccidentalSymbol::*;
 pub use ToneSymbol::*;
@@ -0,0 +1,148 @@
+use crate::prelude::*;
+
+/// A scale that has distinct ascending and descending forms,
+/// like the melodic minor scale in classical theory or many ragas.
+/// - The ascending form is used where no direction is given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asymmetric<A, D> {
+    ascending: A,
+    descending: D,
+}
+
+impl<A, D> Asymmetric<A, D> {
+    pub fn new(ascending: A, descending: D) -> Self {
+        Self {
+            ascending,
+            descending,
+        }
+    }
+
+    /// Returns an `Asymmetric` instance.
+    ///
+    /// # Errors
+    /// - if either form is empty.
+    /// - if the forms do not start on the same number.
+    pub fn try_new(ascending: A, descending: D) -> Result<Self, ScaleError>
+    where
+        A: Scale,
+        D: Scale,
+    {
+        if ascending.is_empty() || descending.is_empty() {
+            return Err(ScaleError::EmptyScale);
+        }
+        if ascending.try_convert(0_usize)? != descending.try_convert(0_usize)? {
+            return Err(ScaleError::MismatchedForms);
+        }
+        Ok(Self::new(ascending, descending))
+    }
+
+    pub fn ascending(&self) -> &A {
+        &self.ascending
+    }
+
+    pub fn descending(&self) -> &D {
+        &self.descending
+    }
+}
+
+impl Asymmetric<Formula, Formula> {
+    /// Returns the classical melodic minor scale, which descends as the natural minor scale.
+    pub fn melodic_minor(key: &Tone) -> Self {
+        Self::new(
+            Formula::named(key, ScaleKind::MelodicMinor),
+            Formula::named(key, ScaleKind::NaturalMinor),
+        )
+    }
+}
+
+impl<A: Scale, D: Scale> Scale for Asymmetric<A, D> {
+    fn convert(&self, number: impl Number) -> usize {
+        self.ascending.convert(number)
+    }
+
+    fn len(&self) -> usize {
+        self.ascending.len()
+    }
+
+    fn convert_toward(&self, number: impl Number, direction: Direction) -> usize {
+        match direction {
+            Direction::Ascending => self.ascending.convert(number),
+            Direction::Descending => self.descending.convert(number),
+        }
+    }
+
+    fn len_toward(&self, direction: Direction) -> usize {
+        match direction {
+            Direction::Ascending => self.ascending.len(),
+            Direction::Descending => self.descending.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn melodic_minor() {
+        let scale = Asymmetric::melodic_minor(&Tone::new(A, Natural));
+        let keyboard = Keyboard::new(Scaled::new(scale, Twelve));
+        let up: Vec<_> = keyboard.ascend(4).take(4).map(|p| p.deref()).collect();
+        assert_eq!(
+            up,
+            vec![
+                Pitch::new(Tone::new(E, Natural), 0),
+                Pitch::new(Tone::new(F, Sharp), 0),
+                Pitch::new(Tone::new(G, Sharp), 0),
+                Pitch::new(Tone::new(A, Natural), 1),
+            ]
+        );
+        let down: Vec<_> = keyboard.descend(7).take(4).map(|p| p.deref()).collect();
+        assert_eq!(
+            down,
+            vec![
+                Pitch::new(Tone::new(A, Natural), 1),
+                Pitch::new(Tone::new(G, Natural), 0),
+                Pitch::new(Tone::new(F, Natural), 0),
+                Pitch::new(Tone::new(E, Natural), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn different_lengths() {
+        let key = Tone::new(C, Natural);
+        let ascending = Formula::new(&key, vec![0, 3, 5, 7, 10]);
+        let descending = Formula::new(&key, vec![0, 2, 3, 5, 7, 9, 10]);
+        let keyboard = Keyboard::new(Scaled::new(Asymmetric::new(ascending, descending), Twelve));
+        assert_eq!(keyboard.ascend(0).nth(5), Some(Pitch::new(&key, 1)));
+        assert_eq!(keyboard.descend(7).count(), 8);
+        assert_eq!(
+            keyboard.descend(7).nth(2),
+            Some(Pitch::new(&Tone::new(A, Natural), 0))
+        );
+    }
+
+    #[test]
+    fn try_new() {
+        let scale = Asymmetric::try_new(
+            Formula::named(&Tone::new(C, Natural), ScaleKind::Major),
+            Formula::named(&Tone::new(D, Natural), ScaleKind::Dorian),
+        );
+        assert_eq!(scale, Err(ScaleError::MismatchedForms));
+        assert_eq!(
+            Asymmetric::try_new(vec![0, 2], vec![]),
+            Err(ScaleError::EmptyScale)
+        );
+        // Unchecked forms that start on different numbers still walk without underflow.
+        let keyboard = Keyboard::new(Scaled::new(
+            Asymmetric::new(vec![2, 4], vec![0, 3]),
+            vec![0, 1, 2, 3, 4, 5],
+        ));
+        let down: Vec<_> = keyboard.descend(2).map(|p| p.deref()).collect();
+        assert_eq!(
+            down,
+            vec![Pitch::new(0, 1), Pitch::new(3, 0), Pitch::new(0, 0)]
+        );
+    }
+}
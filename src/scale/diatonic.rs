@@ -24,9 +24,37 @@ impl Diatonic {
         }
     }
 
+    pub fn key(&self) -> &Tone {
+        &self.key
+    }
+
     pub fn quality(&self) -> Quality {
         self.quality
     }
+
+    pub fn key_signature(&self) -> KeySignature {
+        match self.quality {
+            Quality::Major => KeySignature::from_major(&self.key),
+            Quality::Minor => KeySignature::from_minor(&self.key),
+        }
+    }
+
+    /// Returns the relative key, which shares the key signature.
+    pub fn relative(&self) -> Diatonic {
+        let signature = self.key_signature();
+        match self.quality {
+            Quality::Major => Self::minor(&signature.minor()),
+            Quality::Minor => Self::major(&signature.major()),
+        }
+    }
+
+    /// Returns the parallel key, which shares the key.
+    pub fn parallel(&self) -> Diatonic {
+        match self.quality {
+            Quality::Major => Self::minor(&self.key),
+            Quality::Minor => Self::major(&self.key),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -38,7 +66,7 @@ pub enum Quality {
 impl Scale for Diatonic {
     fn convert(&self, number: impl Number) -> usize {
         let i: usize = self.key.into();
-        i + self.distances.get(number.value()).unwrap().clone()
+        i + *self.distances.get(number.value()).unwrap()
     }
 
     fn len(&self) -> usize {
@@ -66,6 +94,18 @@ mod tests {
         assert_eq!(tones.next(), None);
     }
 
+    #[test]
+    fn relative_and_parallel() {
+        let scale = super::Diatonic::major(&Tone::new(E, Flat));
+        assert_eq!(scale.key_signature().value(), -3);
+        let relative = scale.relative();
+        assert_eq!(relative.key(), &Tone::new(C, Natural));
+        assert_eq!(relative.quality(), super::Quality::Minor);
+        let parallel = scale.parallel();
+        assert_eq!(parallel.key(), &Tone::new(E, Flat));
+        assert_eq!(parallel.key_signature().value(), -6);
+    }
+
     #[test]
     fn minor() {
         let scale = super::Diatonic::minor(&Tone::new(A, Natural));
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::prelude::*;
+
+/// A scale on a 12 tone octave that described by a key and semitone distances from the key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Formula {
+    key: Tone,
+    distances: Vec<usize>,
+}
+
+impl Formula {
+    /// Returns a `Formula` instance.
+    /// # Panics
+    /// - if `distances` are invalid. Use `try_new` for distances that may be invalid.
+    pub fn new(key: &Tone, distances: Vec<usize>) -> Formula {
+        Self::try_new(key, distances).unwrap()
+    }
+
+    /// Returns a `Formula` instance.
+    ///
+    /// # Errors
+    /// - if `distances` is empty, or does not start from 0 for the key.
+    /// - if `distances` is not strictly increasing, or spans more than an octave.
+    pub fn try_new(key: &Tone, distances: Vec<usize>) -> Result<Formula, ScaleError> {
+        match distances.first() {
+            None => return Err(ScaleError::EmptyScale),
+            Some(0) => {}
+            Some(_) => return Err(ScaleError::MissingKey),
+        }
+        if let Some(i) = distances.windows(2).position(|w| w[0] >= w[1]) {
+            return Err(ScaleError::NotIncreasing(i + 1));
+        }
+        if let Some(i) = distances.iter().position(|&d| d >= 12) {
+            return Err(ScaleError::OutOfOctave(i));
+        }
+        Ok(Self {
+            key: *key,
+            distances,
+        })
+    }
+
+    pub fn named(key: &Tone, kind: ScaleKind) -> Formula {
+        Self::new(key, kind.distances().to_vec())
+    }
+
+    /// Returns a `Formula` whose degrees are altered degrees of the major scale,
+    /// like "1 2 b3 4 5 6 b7".
+    /// - Returns `None` if any degree falls below the key, the first degree is not the key,
+    ///   or the degrees are not ascending within an octave.
+    pub fn from_degrees(key: &Tone, degrees: &[AlteredDegree]) -> Option<Formula> {
+        let major = ScaleKind::Major.distances();
+        let distances = degrees
+            .iter()
+            .map(|d| {
+                let number = Number::value(&d.degree());
+                let distance = major[number % 7] as i32 + 12 * (number / 7) as i32;
+                usize::try_from(distance + d.alteration()).ok()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Self::try_new(key, distances).ok()
+    }
+
+    pub fn key(&self) -> &Tone {
+        &self.key
+    }
+
+    pub fn distances(&self) -> &[usize] {
+        &self.distances
+    }
+
+    /// Returns a `ScaleKind` whose distances are same as this scale.
+    /// - Returns `None` if no named scale is matched.
+    pub fn kind(&self) -> Option<ScaleKind> {
+        ScaleKind::enumerate()
+            .find(|k| k.distances() == self.distances.as_slice())
+            .copied()
+    }
+}
+
+impl From<&Diatonic> for Formula {
+    fn from(value: &Diatonic) -> Self {
+        let kind = match value.quality() {
+            DiatonicQuality::Major => ScaleKind::Major,
+            DiatonicQuality::Minor => ScaleKind::NaturalMinor,
+        };
+        Self::named(value.key(), kind)
+    }
+}
+
+impl Scale for Formula {
+    /// Returns a number of `number`, where a number past the last degree is in upper octaves.
+    fn convert(&self, number: impl Number) -> usize {
+        let i: usize = self.key.into();
+        let len = self.distances.len();
+        let value = number.value();
+        i + self.distances[value % len] + 12 * (value / len)
+    }
+
+    /// Returns a number of `number` as `convert` does.
+    /// - Returns an error if this scale has no degrees.
+    fn try_convert(&self, number: impl Number) -> Result<usize, LookupError> {
+        match self.distances.is_empty() {
+            true => Err(LookupError::Empty),
+            false => Ok(self.convert(number)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.distances.len()
+    }
+}
+
+/// Named scales, ordered from the most common.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleKind {
+    Major,
+    NaturalMinor,
+    HarmonicMinor,
+    MelodicMinor,
+    MajorPentatonic,
+    MinorPentatonic,
+    Dorian,
+    Mixolydian,
+    Lydian,
+    Phrygian,
+    Locrian,
+    Blues,
+    LydianDominant,
+    Altered,
+    PhrygianDominant,
+    HarmonicMajor,
+    DorianFlat2,
+    LydianAugmented,
+    MixolydianFlat6,
+    LocrianNatural2,
+    WholeTone,
+    HalfWholeDiminished,
+    WholeHalfDiminished,
+    Chromatic,
+}
+
+static ENUM_SCALE_KIND: Lazy<[ScaleKind; 24]> = Lazy::new(|| {
+    use ScaleKind::*;
+    [
+        Major,
+        NaturalMinor,
+        HarmonicMinor,
+        MelodicMinor,
+        MajorPentatonic,
+        MinorPentatonic,
+        Dorian,
+        Mixolydian,
+        Lydian,
+        Phrygian,
+        Locrian,
+        Blues,
+        LydianDominant,
+        Altered,
+        PhrygianDominant,
+        HarmonicMajor,
+        DorianFlat2,
+        LydianAugmented,
+        MixolydianFlat6,
+        LocrianNatural2,
+        WholeTone,
+        HalfWholeDiminished,
+        WholeHalfDiminished,
+        Chromatic,
+    ]
+});
+
+static KIND_TO_DISTANCES: Lazy<HashMap<ScaleKind, Vec<usize>>> = Lazy::new(|| {
+    use ScaleKind::*;
+    let mut hash = HashMap::<ScaleKind, Vec<usize>>::default();
+    hash.insert(Major, vec![0, 2, 4, 5, 7, 9, 11]);
+    hash.insert(NaturalMinor, vec![0, 2, 3, 5, 7, 8, 10]);
+    hash.insert(HarmonicMinor, vec![0, 2, 3, 5, 7, 8, 11]);
+    hash.insert(MelodicMinor, vec![0, 2, 3, 5, 7, 9, 11]);
+    hash.insert(MajorPentatonic, vec![0, 2, 4, 7, 9]);
+    hash.insert(MinorPentatonic, vec![0, 3, 5, 7, 10]);
+    hash.insert(Dorian, vec![0, 2, 3, 5, 7, 9, 10]);
+    hash.insert(Mixolydian, vec![0, 2, 4, 5, 7, 9, 10]);
+    hash.insert(Lydian, vec![0, 2, 4, 6, 7, 9, 11]);
+    hash.insert(Phrygian, vec![0, 1, 3, 5, 7, 8, 10]);
+    hash.insert(Locrian, vec![0, 1, 3, 5, 6, 8, 10]);
+    hash.insert(Blues, vec![0, 3, 5, 6, 7, 10]);
+    hash.insert(LydianDominant, vec![0, 2, 4, 6, 7, 9, 10]);
+    hash.insert(Altered, vec![0, 1, 3, 4, 6, 8, 10]);
+    hash.insert(PhrygianDominant, vec![0, 1, 4, 5, 7, 8, 10]);
+    hash.insert(HarmonicMajor, vec![0, 2, 4, 5, 7, 8, 11]);
+    hash.insert(DorianFlat2, vec![0, 1, 3, 5, 7, 9, 10]);
+    hash.insert(LydianAugmented, vec![0, 2, 4, 6, 8, 9, 11]);
+    hash.insert(MixolydianFlat6, vec![0, 2, 4, 5, 7, 8, 10]);
+    hash.insert(LocrianNatural2, vec![0, 2, 3, 5, 6, 8, 10]);
+    hash.insert(WholeTone, vec![0, 2, 4, 6, 8, 10]);
+    hash.insert(HalfWholeDiminished, vec![0, 1, 3, 4, 6, 7, 9, 10]);
+    hash.insert(WholeHalfDiminished, vec![0, 2, 3, 5, 6, 8, 9, 11]);
+    hash.insert(Chromatic, (0..12).collect());
+    hash
+});
+
+impl ScaleKind {
+    pub fn enumerate() -> std::slice::Iter<'static, ScaleKind> {
+        ENUM_SCALE_KIND.iter()
+    }
+
+    /// Returns semitone distances of each degree from the key.
+    pub fn distances(&self) -> &'static [usize] {
+        KIND_TO_DISTANCES.get(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn named() {
+        let scale = Formula::named(&Tone::new(D, Natural), ScaleKind::Dorian);
+        let keyboard = Keyboard::new(Scaled::new(scale, Twelve));
+        let mut tones = keyboard.class_iter();
+        assert_eq!(tones.next(), Some(&Tone::new(D, Natural)));
+        assert_eq!(tones.next(), Some(&Tone::new(E, Natural)));
+        assert_eq!(tones.next(), Some(&Tone::new(F, Natural)));
+        assert_eq!(tones.next(), Some(&Tone::new(G, Natural)));
+        assert_eq!(tones.next(), Some(&Tone::new(A, Natural)));
+        assert_eq!(tones.next(), Some(&Tone::new(B, Natural)));
+        assert_eq!(tones.next(), Some(&Tone::new(C, Natural)));
+        assert_eq!(tones.next(), None);
+    }
+
+    #[test]
+    fn from_degrees() {
+        let degrees: Vec<AlteredDegree> = ["1", "2", "b3", "4", "5", "b6", "7"]
+            .iter()
+            .map(|d| d.parse().unwrap())
+            .collect();
+        let scale = Formula::from_degrees(&Tone::new(C, Natural), &degrees).unwrap();
+        assert_eq!(scale.kind(), Some(ScaleKind::HarmonicMinor));
+        let degrees = ["b1".parse().unwrap()];
+        assert_eq!(
+            Formula::from_degrees(&Tone::new(C, Natural), &degrees),
+            None
+        );
+        let degrees = ["2".parse().unwrap(), "3".parse().unwrap()];
+        assert_eq!(
+            Formula::from_degrees(&Tone::new(C, Natural), &degrees),
+            None
+        );
+    }
+
+    #[test]
+    fn try_new() {
+        let key = Tone::new(C, Natural);
+        assert!(Formula::try_new(&key, vec![0, 2, 4]).is_ok());
+        assert_eq!(Formula::try_new(&key, vec![]), Err(ScaleError::EmptyScale));
+        assert_eq!(
+            Formula::try_new(&key, vec![0, 2, 2]),
+            Err(ScaleError::NotIncreasing(2))
+        );
+        assert_eq!(
+            Formula::try_new(&key, vec![0, 2, 12]),
+            Err(ScaleError::OutOfOctave(2))
+        );
+        assert_eq!(
+            Formula::try_new(&key, vec![2, 4]),
+            Err(ScaleError::MissingKey)
+        );
+        let scale = Formula::new(&key, vec![0, 4, 7]);
+        assert_eq!(scale.convert(4_usize), 16);
+        assert_eq!(scale.try_convert(4_usize), Ok(16));
+    }
+
+    #[test]
+    fn kind() {
+        let scale = Formula::new(&Tone::new(C, Natural), vec![0, 2, 3, 5, 7, 8, 11]);
+        assert_eq!(scale.kind(), Some(ScaleKind::HarmonicMinor));
+        let scale = Formula::new(&Tone::new(C, Natural), vec![0, 1, 2]);
+        assert_eq!(scale.kind(), None);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::prelude::*;
+
+/// A scale that contains every given tone.
+#[derive(Debug)]
+pub struct Candidate {
+    scaled: Scaled<Formula, Twelve>,
+    extra: usize,
+}
+
+impl Candidate {
+    pub fn scaled(&self) -> &Scaled<Formula, Twelve> {
+        &self.scaled
+    }
+
+    pub fn into_scaled(self) -> Scaled<Formula, Twelve> {
+        self.scaled
+    }
+
+    /// Returns a number of tones of this scale that are not in the given tones.
+    pub fn extra(&self) -> usize {
+        self.extra
+    }
+}
+
+/// Returns every scale and key that contains all of given `tones`.
+/// - Candidates are ranked by the fewest extra tones, then by whether the key is one of
+///   the given tones, then by how common the scale is.
+/// - Returns an empty vector if no tones are given.
+pub fn identify<'a>(tones: impl IntoIterator<Item = &'a Tone>) -> Vec<Candidate> {
+    let tones: Vec<&Tone> = tones.into_iter().collect();
+    if tones.is_empty() {
+        return vec![];
+    }
+    let classes: Vec<usize> = tones.iter().map(|&&t| usize::from(t) % 12).collect();
+    let mut ranked = vec![];
+    for key_class in 0..12 {
+        let key = tones
+            .iter()
+            .find(|&&&t| usize::from(t) % 12 == key_class)
+            .map(|&&t| t)
+            .unwrap_or_else(|| {
+                let accidental = if key_class == 6 {
+                    AccidentalSymbol::Sharp
+                } else {
+                    AccidentalSymbol::Flat
+                };
+                Tone::spell(key_class, accidental)
+            });
+        for (rank, kind) in ScaleKind::enumerate().enumerate() {
+            let scale: Vec<usize> = kind
+                .distances()
+                .iter()
+                .map(|d| (key_class + d) % 12)
+                .collect();
+            if !classes.iter().all(|c| scale.contains(c)) {
+                continue;
+            }
+            let extra = scale.iter().filter(|c| !classes.contains(c)).count();
+            let is_key_given = classes.contains(&key_class);
+            let candidate = Candidate {
+                scaled: Scaled::new(Formula::named(&key, *kind), Twelve),
+                extra,
+            };
+            ranked.push(((extra, !is_key_given, rank, key_class), candidate));
+        }
+    }
+    ranked.sort_by_key(|(order, _)| *order);
+    ranked.into_iter().map(|(_, c)| c).collect()
+}
+
+/// Returns every scale and key that contains all classes of given `pitches`.
+/// - See [`identify`] for the ranking.
+pub fn identify_pitches<'a>(pitches: impl IntoIterator<Item = &'a Pitch<Tone>>) -> Vec<Candidate> {
+    identify(pitches.into_iter().map(|p| p.class()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn identify() {
+        let tones: Vec<Tone> = ["C", "D", "E", "F", "G", "A", "B"]
+            .iter()
+            .map(|t| t.parse().unwrap())
+            .collect();
+        let candidates = super::identify(&tones);
+        let first = candidates.first().unwrap();
+        assert_eq!(first.extra(), 0);
+        assert_eq!(first.scaled().scale().key(), &Tone::new(C, Natural));
+        assert_eq!(first.scaled().scale().kind(), Some(ScaleKind::Major));
+        let second = candidates.get(1).unwrap();
+        assert_eq!(second.scaled().scale().key(), &Tone::new(A, Natural));
+        assert_eq!(
+            second.scaled().scale().kind(),
+            Some(ScaleKind::NaturalMinor)
+        );
+        assert!(candidates.iter().all(|c| c.extra() == 0 || c.extra() == 5));
+        assert!(super::identify(&[]).is_empty());
+        assert!(super::identify_pitches(&[]).is_empty());
+    }
+
+    #[test]
+    fn identify_pitches() {
+        let pitches = [
+            Pitch::new("Bb".parse().unwrap(), 3),
+            Pitch::new("D".parse().unwrap(), 4),
+            Pitch::new("F".parse().unwrap(), 4),
+            Pitch::new("E".parse().unwrap(), 4),
+        ];
+        let candidates = super::identify_pitches(&pitches);
+        let first = candidates.first().unwrap();
+        assert_eq!(first.extra(), 3);
+        assert_eq!(first.scaled().scale().key(), &Tone::new(F, Natural));
+        assert_eq!(first.scaled().scale().kind(), Some(ScaleKind::Major));
+        assert!(candidates.iter().any(|c| {
+            c.scaled().scale().key() == &Tone::new(B, Flat)
+                && c.scaled().scale().kind() == Some(ScaleKind::Lydian)
+        }));
+    }
+}
@@ -0,0 +1,249 @@
+use crate::prelude::*;
+
+/// A key signature, represented as a number of sharps (positive) or flats (negative).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct KeySignature(i32);
+
+const SHARPS: [ToneSymbol; 7] = {
+    use ToneSymbol::*;
+    [F, C, G, D, A, E, B]
+};
+
+const FLATS: [ToneSymbol; 7] = {
+    use ToneSymbol::*;
+    [B, E, A, D, G, C, F]
+};
+
+/// Tonics of major keys from 7 flats to 7 sharps.
+const MAJOR_TONICS: [(ToneSymbol, AccidentalSymbol); 15] = {
+    use AccidentalSymbol::*;
+    use ToneSymbol::*;
+    [
+        (C, Flat),
+        (G, Flat),
+        (D, Flat),
+        (A, Flat),
+        (E, Flat),
+        (B, Flat),
+        (F, Natural),
+        (C, Natural),
+        (G, Natural),
+        (D, Natural),
+        (A, Natural),
+        (E, Natural),
+        (B, Natural),
+        (F, Sharp),
+        (C, Sharp),
+    ]
+};
+
+/// Tonics of minor keys from 7 flats to 7 sharps.
+const MINOR_TONICS: [(ToneSymbol, AccidentalSymbol); 15] = {
+    use AccidentalSymbol::*;
+    use ToneSymbol::*;
+    [
+        (A, Flat),
+        (E, Flat),
+        (B, Flat),
+        (F, Natural),
+        (C, Natural),
+        (G, Natural),
+        (D, Natural),
+        (A, Natural),
+        (E, Natural),
+        (B, Natural),
+        (F, Sharp),
+        (C, Sharp),
+        (G, Sharp),
+        (D, Sharp),
+        (A, Sharp),
+    ]
+};
+
+impl KeySignature {
+    /// Returns a `KeySignature` instance.
+    /// - Returns `None` if `accidentals` is out of `-7..=7`.
+    pub fn new(accidentals: i32) -> Option<Self> {
+        (-7..=7).contains(&accidentals).then_some(Self(accidentals))
+    }
+
+    /// Returns a `KeySignature` of the major key on given `tonic`.
+    /// - An enharmonic spelling like "D#" falls back to the signature with fewer accidentals.
+    pub fn from_major(tonic: &Tone) -> Self {
+        Self::from_tonic(tonic, &MAJOR_TONICS)
+    }
+
+    /// Returns a `KeySignature` of the minor key on given `tonic`.
+    /// - An enharmonic spelling like "Gb" falls back to the signature with fewer accidentals.
+    pub fn from_minor(tonic: &Tone) -> Self {
+        Self::from_tonic(tonic, &MINOR_TONICS)
+    }
+
+    /// Returns a `KeySignature` of given church mode, like "D dorian".
+    /// - Returns `None` if `scale` is not a church mode.
+    pub fn from_mode(scale: &Formula) -> Option<Self> {
+        use ScaleKind::*;
+        let offset = match scale.kind()? {
+            Major => 0,
+            Dorian => 2,
+            Phrygian => 4,
+            Lydian => 5,
+            Mixolydian => 7,
+            NaturalMinor => 9,
+            Locrian => 11,
+            _ => return None,
+        };
+        let key: usize = (*scale.key()).into();
+        let tonic = Tone::spell(key + 12 - offset, *scale.key().accidental());
+        Some(Self::from_major(&tonic))
+    }
+
+    fn from_tonic(tonic: &Tone, tonics: &[(ToneSymbol, AccidentalSymbol); 15]) -> Self {
+        let tones = tonics.iter().map(|&(t, a)| Tone::written(t, a)).enumerate();
+        let nearest = |(i, _): &(usize, Tone)| (*i as i32 - 7).abs();
+        let class = usize::from(*tonic) % 12;
+        let (index, _) = tones
+            .clone()
+            .filter(|(_, t)| t == tonic)
+            .min_by_key(nearest)
+            .or_else(|| {
+                tones
+                    .filter(|(_, t)| usize::from(*t) % 12 == class)
+                    .min_by_key(nearest)
+            })
+            .unwrap();
+        Self(index as i32 - 7)
+    }
+
+    /// Returns a number of accidentals, positive for sharps and negative for flats.
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+
+    /// Returns the accidental that this signature puts, or `Natural` if it has none.
+    pub fn accidental(&self) -> AccidentalSymbol {
+        match self.0.signum() {
+            1 => AccidentalSymbol::Sharp,
+            -1 => AccidentalSymbol::Flat,
+            _ => AccidentalSymbol::Natural,
+        }
+    }
+
+    /// Returns tone symbols that are altered by this signature, in the written order.
+    pub fn symbols(&self) -> &'static [ToneSymbol] {
+        let count = self.0.unsigned_abs() as usize;
+        if self.0 > 0 {
+            &SHARPS[..count]
+        } else {
+            &FLATS[..count]
+        }
+    }
+
+    /// Returns a `Tone` of given `symbol` under this signature.
+    pub fn apply(&self, symbol: ToneSymbol) -> Tone {
+        if self.symbols().contains(&symbol) {
+            Tone::written(symbol, self.accidental())
+        } else {
+            Tone::new(symbol, AccidentalSymbol::Natural)
+        }
+    }
+
+    /// Returns a tonic of the major key of this signature, spelled as written like "Cb".
+    pub fn major(&self) -> Tone {
+        let (tone, accidental) = MAJOR_TONICS[(self.0 + 7) as usize];
+        Tone::written(tone, accidental)
+    }
+
+    /// Returns a tonic of the minor key of this signature, spelled as written.
+    pub fn minor(&self) -> Tone {
+        let (tone, accidental) = MINOR_TONICS[(self.0 + 7) as usize];
+        Tone::written(tone, accidental)
+    }
+
+    /// Returns the next signature clockwise on the circle of fifths, a fifth above.
+    /// - 7 sharps wraps to its enharmonic signature, 4 flats.
+    pub fn sharpen(&self) -> Self {
+        Self::wrap(self.0 + 1)
+    }
+
+    /// Returns the next signature counterclockwise on the circle of fifths, a fifth below.
+    /// - 7 flats wraps to its enharmonic signature, 4 sharps.
+    pub fn flatten(&self) -> Self {
+        Self::wrap(self.0 - 1)
+    }
+
+    fn wrap(value: i32) -> Self {
+        match value {
+            v if v > 7 => Self(v - 12),
+            v if v < -7 => Self(v + 12),
+            v => Self(v),
+        }
+    }
+}
+
+impl From<&Diatonic> for KeySignature {
+    fn from(value: &Diatonic) -> Self {
+        value.key_signature()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn from_tonic() {
+        assert_eq!(KeySignature::from_major(&Tone::new(E, Flat)).value(), -3);
+        assert_eq!(KeySignature::from_major(&Tone::new(F, Sharp)).value(), 6);
+        assert_eq!(KeySignature::from_major(&Tone::new(G, Flat)).value(), -6);
+        assert_eq!(KeySignature::from_major(&Tone::new(D, Sharp)).value(), -3);
+        assert_eq!(KeySignature::from_major(&Tone::new(B, Natural)).value(), 5);
+        assert_eq!(KeySignature::from_minor(&Tone::new(F, Sharp)).value(), 3);
+    }
+
+    #[test]
+    fn from_mode() {
+        let scale = Formula::named(&Tone::new(D, Natural), ScaleKind::Dorian);
+        assert_eq!(KeySignature::from_mode(&scale), KeySignature::new(0));
+        let scale = Formula::named(&Tone::new(E, Flat), ScaleKind::Lydian);
+        assert_eq!(KeySignature::from_mode(&scale), KeySignature::new(-2));
+        let scale = Formula::named(&Tone::new(C, Natural), ScaleKind::Blues);
+        assert_eq!(KeySignature::from_mode(&scale), None);
+    }
+
+    #[test]
+    fn symbols() {
+        let signature = KeySignature::new(-3).unwrap();
+        assert_eq!(signature.symbols(), &[B, E, A]);
+        assert_eq!(signature.apply(A), Tone::new(A, Flat));
+        assert_eq!(signature.apply(D), Tone::new(D, Natural));
+        assert_eq!(signature.major(), Tone::new(E, Flat));
+        assert_eq!(signature.minor(), Tone::new(C, Natural));
+    }
+
+    #[test]
+    fn circle_of_fifths() {
+        let signature = KeySignature::new(0).unwrap();
+        assert_eq!(signature.sharpen().major(), Tone::new(G, Natural));
+        assert_eq!(signature.flatten().major(), Tone::new(F, Natural));
+        assert_eq!(KeySignature::new(7).unwrap().sharpen().value(), -4);
+        assert_eq!(KeySignature::new(-7).unwrap().flatten().value(), 4);
+    }
+
+    #[test]
+    fn extremes() {
+        let flats = KeySignature::new(-7).unwrap();
+        assert_eq!(flats.major(), Tone::written(C, Flat));
+        assert_eq!(flats.minor(), Tone::new(A, Flat));
+        assert_eq!(flats.apply(F), Tone::written(F, Flat));
+        assert_eq!(KeySignature::from_major(&flats.major()), flats);
+        let sharps = KeySignature::new(7).unwrap();
+        assert_eq!(sharps.major(), Tone::new(C, Sharp));
+        assert_eq!(sharps.minor(), Tone::new(A, Sharp));
+        assert_eq!(sharps.apply(B), Tone::written(B, Sharp));
+        assert_eq!(KeySignature::from_minor(&sharps.minor()), sharps);
+        let relative = Diatonic::minor(&Tone::new(A, Flat)).relative();
+        assert_eq!(relative.key(), &Tone::written(C, Flat));
+        assert_eq!(relative.key_signature(), flats);
+    }
+}
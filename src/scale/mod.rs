@@ -1,5 +1,15 @@
+mod asymmetric;
 mod diatonic;
+mod formula;
+mod identify;
+mod key_signature;
+mod stepped;
 // mod pentatonic;
 
-pub use diatonic::Diatonic;
+pub use asymmetric::Asymmetric;
+pub use diatonic::{Diatonic, Quality as DiatonicQuality};
+pub use formula::{Formula, ScaleKind};
+pub use identify::{identify, identify_pitches, Candidate};
+pub use key_signature::KeySignature;
+pub use stepped::{Step, StepPattern, Stepped};
 // pub use pentatonic::Pentatonic;
@@ -0,0 +1,159 @@
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    Large,
+    Small,
+}
+
+/// A pattern of large and small steps, like "LLsLLLs" for the major scale.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StepPattern(Vec<Step>);
+
+impl StepPattern {
+    pub fn new(steps: Vec<Step>) -> Self {
+        Self(steps)
+    }
+
+    /// Returns the pattern of the major scale, "LLsLLLs".
+    pub fn major() -> Self {
+        use Step::*;
+        Self(vec![Large, Large, Small, Large, Large, Large, Small])
+    }
+
+    /// Returns the pattern of the natural minor scale, "LsLLsLL".
+    pub fn minor() -> Self {
+        Self::major().rotate(5)
+    }
+
+    /// Returns a mode of this pattern that starts on given 0-based `degree`.
+    pub fn rotate(&self, degree: usize) -> Self {
+        let mut steps = self.0.clone();
+        if !steps.is_empty() {
+            steps.rotate_left(degree % self.0.len());
+        }
+        Self(steps)
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.0
+    }
+
+    /// Returns a number of given `step` in this pattern.
+    pub fn count(&self, step: Step) -> usize {
+        self.0.iter().filter(|&&s| s == step).count()
+    }
+
+    /// Returns sizes of the large and the small step that fill `divisions` exactly.
+    /// - The large step is always larger than the small step.
+    /// - If several sizes fit, the one whose ratio is the closest to 2:1 is picked,
+    ///   which is the ratio of the 12 tone equal temperament.
+    /// - Returns `None` if no size fits.
+    pub fn fit(&self, divisions: usize) -> Option<(usize, usize)> {
+        let large = self.count(Step::Large);
+        let small = self.count(Step::Small);
+        (1..=divisions)
+            .filter_map(|s| {
+                let rest = divisions.checked_sub(small * s)?;
+                let l = rest.checked_div(large)?;
+                (l > s && l * large == rest).then_some((l, s))
+            })
+            .min_by(|a, b| {
+                let hardness = |(l, s): &(usize, usize)| (*l as f64 / *s as f64 - 2.0).abs();
+                hardness(a).total_cmp(&hardness(b))
+            })
+    }
+}
+
+/// A scale that built from a `StepPattern`, which can be realized on any `Octave`,
+/// like the major scale on 19 or 31 equal divisions of the octave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stepped {
+    tonic: usize,
+    distances: Vec<usize>,
+}
+
+impl Stepped {
+    /// Returns a `Stepped` whose steps have given sizes.
+    /// - `tonic` is a number of the first class on the `Octave`.
+    pub fn new(pattern: &StepPattern, large: usize, small: usize, tonic: usize) -> Self {
+        let mut distances = vec![0];
+        for step in pattern
+            .steps()
+            .iter()
+            .take(pattern.steps().len().saturating_sub(1))
+        {
+            let size = match step {
+                Step::Large => large,
+                Step::Small => small,
+            };
+            distances.push(distances.last().unwrap() + size);
+        }
+        Self { tonic, distances }
+    }
+
+    /// Returns a `Stepped` that fits `octave`, starting on given `tonic`.
+    /// - Returns `None` if `tonic` is not found on `octave`, or no step sizes fit.
+    pub fn fit<O: Octave>(
+        pattern: &StepPattern,
+        octave: &O,
+        tonic: &O::PitchClass,
+    ) -> Option<Self> {
+        let (large, small) = pattern.fit(octave.len())?;
+        let tonic = octave.get_number(tonic)?;
+        Some(Self::new(pattern, large, small, tonic))
+    }
+
+    pub fn distances(&self) -> &[usize] {
+        &self.distances
+    }
+}
+
+impl Scale for Stepped {
+    fn convert(&self, number: impl Number) -> usize {
+        self.tonic + self.distances[number.value()]
+    }
+
+    fn len(&self) -> usize {
+        self.distances.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn fit() {
+        let major = StepPattern::major();
+        assert_eq!(major.fit(12), Some((2, 1)));
+        assert_eq!(major.fit(19), Some((3, 2)));
+        assert_eq!(major.fit(31), Some((5, 3)));
+        assert_eq!(major.fit(53), Some((9, 4)));
+        assert_eq!(major.fit(7), None);
+        let pentatonic: StepPattern = "ssLsL".parse().unwrap();
+        assert_eq!(pentatonic.fit(19), Some((5, 3)));
+    }
+
+    #[test]
+    fn twelve() {
+        let tonic = Tone::new(A, Natural);
+        let scale = Stepped::fit(&StepPattern::minor(), &Twelve, &tonic).unwrap();
+        let keyboard = Keyboard::new(Scaled::new(scale, Twelve));
+        let tones: Vec<_> = keyboard.class_iter().copied().collect();
+        let expected: Vec<Tone> = ["A", "B", "C", "D", "E", "F", "G"]
+            .iter()
+            .map(|t| t.parse().unwrap())
+            .collect();
+        assert_eq!(tones, expected);
+    }
+
+    #[test]
+    fn edo() {
+        let edo = Edo::new(31);
+        let scale = Stepped::fit(&StepPattern::major(), &edo, &0).unwrap();
+        assert_eq!(scale.distances(), &[0, 5, 10, 13, 18, 23, 28]);
+        let keyboard = Keyboard::new(Scaled::new(scale, edo));
+        assert_eq!(keyboard.get_pitch(&9_usize), Pitch::new(&10, 1));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::core::{Cycle, Octave};
+
+/// An equal division of a period, like "19-EDO" or "13-ED3".
+/// - Each class is represented as a number of steps from the first class.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edo {
+    classes: Vec<usize>,
+    period: f64,
+}
+
+impl Edo {
+    /// Returns an equal division of the octave.
+    pub fn new(divisions: usize) -> Self {
+        Self::with_period(divisions, 2.0)
+    }
+
+    /// Returns an equal division of given frequency ratio `period`.
+    pub fn with_period(divisions: usize, period: f64) -> Self {
+        Self {
+            classes: (0..divisions).collect(),
+            period,
+        }
+    }
+
+    /// Returns the Bohlen-Pierce scale, 13 equal divisions of the tritave (3:1).
+    pub fn bohlen_pierce() -> Self {
+        Self::with_period(13, 3.0)
+    }
+}
+
+impl Octave for Edo {
+    type PitchClass = usize;
+
+    fn get_class(&self, number: &Cycle) -> &Self::PitchClass {
+        self.classes.get_class(number)
+    }
+
+    fn get_number(&self, class: &Self::PitchClass) -> Option<usize> {
+        (*class < self.classes.len()).then_some(*class)
+    }
+
+    fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    fn period(&self) -> Option<f64> {
+        Some(self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn bohlen_pierce() {
+        let key = Keyboard::new(Edo::bohlen_pierce());
+        assert_eq!(key.get_pitch(&13_usize), Pitch::new(&0, 1));
+        let freq = key.frequency(&Pitch::new(0, 1), 100.0).unwrap();
+        assert!((freq - 300.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nineteen() {
+        let key = Keyboard::new(Edo::new(19));
+        assert_eq!(key.get_pitch(&20_usize), Pitch::new(&1, 1));
+        let freq = key.frequency(&Pitch::new(19, 0), 100.0);
+        assert_eq!(freq, None);
+    }
+}
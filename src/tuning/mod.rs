@@ -0,0 +1,10 @@
+//! Modules that represents tunings other than 12 equal temperament,
+//! like "19-EDO", the Bohlen-Pierce scale or stretched octaves.
+
+mod edo;
+mod ratios;
+mod stretched;
+
+pub use edo::*;
+pub use ratios::*;
+pub use stretched::*;
@@ -0,0 +1,85 @@
+use crate::core::{Cycle, Octave};
+
+/// An `Octave` whose classes have explicit frequency ratios, like just intonation.
+/// - The period may be any ratio, or may be absent for a scale that does not repeat.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ratios<V> {
+    classes: Vec<V>,
+    ratios: Vec<f64>,
+    period: Option<f64>,
+}
+
+impl<V> Ratios<V> {
+    /// Returns a `Ratios` that repeats at given `period`.
+    /// - `classes` are pairs of a class and its ratio from the first class.
+    pub fn new(classes: Vec<(V, f64)>, period: f64) -> Self {
+        Self::with_period(classes, Some(period))
+    }
+
+    /// Returns a `Ratios` that does not repeat.
+    /// - Only pitches on the period 0 have a frequency.
+    pub fn non_repeating(classes: Vec<(V, f64)>) -> Self {
+        Self::with_period(classes, None)
+    }
+
+    fn with_period(classes: Vec<(V, f64)>, period: Option<f64>) -> Self {
+        let (classes, ratios) = classes.into_iter().unzip();
+        Self {
+            classes,
+            ratios,
+            period,
+        }
+    }
+}
+
+impl<V: PartialEq + PartialOrd> Octave for Ratios<V> {
+    type PitchClass = V;
+
+    fn get_class(&self, number: &Cycle) -> &Self::PitchClass {
+        self.classes.get_class(number)
+    }
+
+    fn get_number(&self, class: &Self::PitchClass) -> Option<usize> {
+        self.classes.get_number(class)
+    }
+
+    fn len(&self) -> usize {
+        self.classes.len()
+    }
+
+    fn period(&self) -> Option<f64> {
+        self.period
+    }
+
+    fn ratio(&self, class: &Self::PitchClass) -> Option<f64> {
+        self.get_number(class).map(|n| self.ratios[n])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn just_tritave() {
+        let classes = vec![(0, 1.0), (1, 25.0 / 21.0), (2, 9.0 / 7.0), (3, 7.0 / 5.0)];
+        let key = Keyboard::new(Ratios::new(classes, 3.0));
+        assert_eq!(key.frequency(&Pitch::new(2, 1), 70.0), Some(270.0));
+    }
+
+    #[test]
+    fn non_repeating() {
+        let classes = vec![("a", 1.0), ("b", 1.5), ("c", 2.5)];
+        let key = Keyboard::new(Ratios::non_repeating(classes));
+        assert_eq!(key.frequency(&Pitch::new("c", 0), 100.0), Some(250.0));
+        assert_eq!(key.frequency(&Pitch::new("a", 1), 100.0), None);
+        assert_eq!(key.try_get_pitch(&2_usize), Ok(Pitch::new(&"c", 0)));
+        assert_eq!(key.try_get_pitch(&3_usize), Err(LookupError::OutOfRange(3)));
+        assert_eq!(key.as_number(&Pitch::new("b", 1)), None);
+        let key = Keyboard::new(Scaled::new(
+            vec![0, 2],
+            Ratios::non_repeating(vec![("a", 1.0), ("b", 1.5), ("c", 2.5)]),
+        ));
+        assert_eq!(key.ascend(0).count(), 2);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::core::{Cycle, Octave};
+
+/// An `Octave` whose period is stretched (or compressed) to given frequency ratio,
+/// like the octaves of a piano tuned slightly wider than 2:1.
+#[derive(Debug, Clone)]
+pub struct Stretched<O> {
+    inner: O,
+    period: f64,
+}
+
+impl<O> Stretched<O> {
+    pub fn new(inner: O, period: f64) -> Self {
+        Self { inner, period }
+    }
+}
+
+impl<O: Octave> Octave for Stretched<O> {
+    type PitchClass = O::PitchClass;
+
+    fn get_class(&self, number: &Cycle) -> &Self::PitchClass {
+        self.inner.get_class(number)
+    }
+
+    fn get_number(&self, class: &Self::PitchClass) -> Option<usize> {
+        self.inner.get_number(class)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn period(&self) -> Option<f64> {
+        Some(self.period)
+    }
+
+    /// Returns a ratio of the inner `Octave` that scaled to the stretched period.
+    fn ratio(&self, class: &Self::PitchClass) -> Option<f64> {
+        let inner = self.inner.period()?;
+        let ratio = self.inner.ratio(class)?;
+        Some(ratio.powf(self.period.ln() / inner.ln()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn stretched() {
+        let key = Keyboard::new(Stretched::new(Twelve, 2.02));
+        let a: Tone = "A".parse().unwrap();
+        let freq = key.frequency(&Pitch::new(a, 1), 100.0).unwrap();
+        assert!((freq - 100.0 * 2.02_f64.powf(21.0 / 12.0)).abs() < 1e-9);
+    }
+}
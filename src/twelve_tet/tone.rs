@@ -15,6 +15,34 @@ impl Tone {
         Tone { tone, accidental }.normalize()
     }
 
+    /// Returns a `Tone` as written, which keeps a spelling like "Cb" or "E#" that `new`
+    /// respells as "B" or "F".
+    pub fn written(tone: ToneSymbol, accidental: AccidentalSymbol) -> Self {
+        Tone { tone, accidental }
+    }
+
+    /// Returns a `Tone` that is `number` semitones above C.
+    /// - A black key is spelled with given `accidental`, or with a sharp if `Natural` is given.
+    pub fn spell(number: usize, accidental: AccidentalSymbol) -> Self {
+        use AccidentalSymbol::*;
+        use ToneSymbol::*;
+        let naturals = [C, D, E, F, G, A, B];
+        let number = number % 12;
+        if let Some(tone) = naturals.iter().find(|&&t| t as usize == number) {
+            return Tone::new(*tone, Natural);
+        }
+        match accidental {
+            Flat => {
+                let tone = naturals.iter().find(|&&t| t as usize == number + 1);
+                Tone::new(*tone.unwrap(), Flat)
+            }
+            _ => {
+                let tone = naturals.iter().find(|&&t| t as usize + 1 == number);
+                Tone::new(*tone.unwrap(), Sharp)
+            }
+        }
+    }
+
     pub fn tone(&self) -> &ToneSymbol {
         &self.tone
     }
@@ -36,9 +64,10 @@ impl Tone {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(i32)]
 pub enum ToneSymbol {
+    #[default]
     C = 0,
     D = 2,
     E = 4,
@@ -48,29 +77,18 @@ pub enum ToneSymbol {
     B = 11,
 }
 
-impl Default for ToneSymbol {
-    fn default() -> Self {
-        Self::C
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(i32)]
 pub enum AccidentalSymbol {
     Flat = -1,
+    #[default]
     Natural = 0,
     Sharp = 1,
 }
 
-impl Default for AccidentalSymbol {
-    fn default() -> Self {
-        Self::Natural
-    }
-}
-
 impl From<Tone> for usize {
     fn from(value: Tone) -> Self {
-        (value.tone as i32 + value.accidental as i32) as usize
+        (value.tone as i32 + value.accidental as i32).rem_euclid(12) as usize
     }
 }
 
@@ -111,3 +129,18 @@ impl Octave for Twelve {
         12
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use AccidentalSymbol::*;
+    use ToneSymbol::*;
+
+    #[test]
+    fn spell() {
+        assert_eq!(Tone::spell(0, Flat), Tone::new(C, Natural));
+        assert_eq!(Tone::spell(10, Flat), Tone::new(B, Flat));
+        assert_eq!(Tone::spell(10, Sharp), Tone::new(A, Sharp));
+        assert_eq!(Tone::spell(13, Natural), Tone::new(C, Sharp));
+    }
+}